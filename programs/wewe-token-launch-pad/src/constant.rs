@@ -1,7 +1,9 @@
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
-pub const SECONDS_TO_DAYS: i64 = 86400;
-pub const MAXIMUM_BACKERS: u64 = 1000; 
+pub const MAXIMUM_BACKERS: u64 = 1000; // Default for Configs::max_backers
 pub const MINT_DECIMALS: u8 = 9;
+pub const MAX_CONFIG_UPDATE_DELAY: i64 = 30 * 86_400;
+/// Backing window that was hard-coded before it became part of the proposal.
+pub const LEGACY_BACKING_WINDOW: i64 = 3 * 86_400;
 
 pub mod seeds {
     pub const MINT_ACCOUNT: &[u8] = b"mint_soulbound";
@@ -13,6 +15,12 @@ pub mod seeds {
     pub const TOKEN_VAULT: &[u8] = b"token_vault";
     pub const MAKER: &[u8] = b"maker";
     pub const BACKER_PROPOSAL_COUNT: &[u8] = b"backer_proposal_count";
+    pub const PROPOSAL_ESCROW: &[u8] = b"proposal_escrow";
+    pub const TEMP_WSOL: &[u8] = b"temp_wsol";
+    pub const ADMIN_REGISTRY: &[u8] = b"admin_registry";
+    pub const CONFIG_UPDATE: &[u8] = b"config_update";
+    pub const LAUNCH_TEMPLATE: &[u8] = b"launch_template";
+    pub const BACKER_FEE_VAULT: &[u8] = b"backer_fee_vault";
 }
 
 pub mod treasury {
@@ -20,6 +28,12 @@ pub mod treasury {
     pub const ID: Pubkey = pubkey!("76U9hvHNUNn7YV5FekSzDHzqnHETsUpDKq4cMj2dMxNi");
 }
 
+/// Lamports sent here are removed from circulation at the end of the transaction.
+pub mod incinerator {
+    use anchor_lang::{prelude::Pubkey, solana_program::pubkey};
+    pub const ID: Pubkey = pubkey!("1nc1nerator11111111111111111111111111111111");
+}
+
 pub mod chain_service_pubkey {
     use anchor_lang::{prelude::Pubkey, solana_program::pubkey};
     pub const ID: Pubkey = pubkey!("D4VNMB6heKqVyiii4HjK2K7pEC9U3tVuNjCkFr3xNGfe");
@@ -27,6 +41,7 @@ pub mod chain_service_pubkey {
 
 pub mod configure_authority_pubkey {
     use anchor_lang::{prelude::Pubkey, solana_program::pubkey};
+    // Bootstraps the AdminRegistry; afterwards the configurator role lives on-chain
     // In production, this will be the same as the upgrade authority
     // For tests, we use a separate keypair: GAM9nJgT7dYqK67Upn9VETCTkMpZinV4d12A8GW4ejRG
     pub const ID: Pubkey = pubkey!("GAM9nJgT7dYqK67Upn9VETCTkMpZinV4d12A8GW4ejRG");
@@ -35,4 +50,21 @@ pub mod configure_authority_pubkey {
 pub mod wsol_pubkey {
     use anchor_lang::{prelude::Pubkey, solana_program::pubkey};
     pub const ID: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
-}
\ No newline at end of file
+}
+
+/// Bounds DAMM v2 enforces on pool fees; fee numerators are out of 1_000_000_000.
+pub mod pool_fee_bounds {
+    pub const MIN_FEE_NUMERATOR: u64 = 100_000; // 0.01%
+    pub const MAX_FEE_NUMERATOR: u64 = 990_000_000; // 99%
+    pub const BASIS_POINT_MAX: u64 = 10_000;
+    pub const BIN_STEP_BPS_DEFAULT: u16 = 1; // The only dynamic fee bin step DAMM v2 accepts
+    pub const BIN_STEP_BPS_U128_DEFAULT: u128 = 1_844_674_407_370_955; // 1 bps in Q64.64
+    pub const MAX_VARIABLE_FEE_CONTROL: u32 = u16::MAX as u32;
+    pub const MAX_VOLATILITY_ACCUMULATOR: u32 = u16::MAX as u32 * 2;
+}
+
+/// Longest activation delay DAMM v2 accepts for a new pool (31 days).
+pub mod pool_activation_bounds {
+    pub const MAX_ACTIVATION_SLOT_DURATION: u64 = 9_000 * 24 * 31;
+    pub const MAX_ACTIVATION_TIME_DURATION: u64 = 3_600 * 24 * 31;
+}
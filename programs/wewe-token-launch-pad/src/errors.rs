@@ -103,4 +103,85 @@ pub enum ProposalError {
 
     #[msg("Insufficient funds in vault to cover proposal requirements")]
     InsufficientFunds,
+
+    #[msg("Backing amount is below the minimum allowed per user")]
+    BackingAmountTooSmall,
+
+    #[msg("Backing amount is above the maximum allowed per user")]
+    BackingAmountTooBig,
+
+    #[msg("Proposal escrow does not hold the proposal's total backing")]
+    EscrowBalanceMismatch,
+
+    #[msg("Backing duration is outside the configured bounds")]
+    InvalidBackingDuration,
+
+    #[msg("Proposal has already been finalized")]
+    ProposalAlreadyFinalized,
+
+    #[msg("Proposal cannot move from its current status to the requested one")]
+    IllegalStateTransition,
+
+    #[msg("Proposal account is not in the legacy layout")]
+    ProposalAlreadyMigrated,
+
+    #[msg("Withdrawals are locked this close to the backing deadline")]
+    WithdrawalLocked,
+
+    #[msg("Proposal has not been completed yet")]
+    ProposalNotCompleted,
+
+    #[msg("Backer still has tokens to claim")]
+    ClaimableAmountRemaining,
+
+    #[msg("Proposal still has open backer accounts or unrefunded backing")]
+    ProposalNotClosable,
+
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    #[msg("Proposal is frozen")]
+    ProposalFrozen,
+
+    #[msg("Invalid pool fee split")]
+    InvalidFeeSplit,
+
+    #[msg("No backer fees to claim")]
+    NoBackerFeesToClaim,
+
+    #[msg("Pool activation delay is out of bounds")]
+    InvalidActivationDelay,
+
+    #[msg("sqrt_price is outside the tolerance around the raise-derived price")]
+    SqrtPriceOutOfTolerance,
+
+    #[msg("Config is already initialized; queue an update instead")]
+    ConfigAlreadyInitialized,
+
+    #[msg("Config update is still in its timelock")]
+    ConfigUpdateNotReady,
+
+    #[msg("Config update would break a config invariant")]
+    InvalidConfig,
+
+    #[msg("Launch template is retired")]
+    LaunchTemplateRetired,
+
+    #[msg("Deposit would take the raise above its hard cap")]
+    HardCapExceeded,
+
+    #[msg("No oversubscribed backing left to claim")]
+    NoExcessToClaim,
+
+    #[msg("Config account is not in the legacy layout")]
+    ConfigAlreadyMigrated,
+
+    #[msg("Backer account is not in the legacy layout")]
+    BackerAlreadyMigrated,
+
+    #[msg("Proposal has not been finalized as succeeded")]
+    ProposalNotSucceeded,
+
+    #[msg("Launched proposal keeps its pool position and cannot be closed")]
+    PoolPositionOpen,
 }
@@ -1,11 +1,22 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{
+    admin_registry::AdminRole,
+    config::{
+        ActivationType, AllocationMode, PoolFeeSchedule, PoolFeeSplit, ProposalTerms,
+        ResidualPolicy, ResidualQuotePolicy,
+    },
+    config_update::ConfigParam,
+    proposal::RejectionCause,
+};
+
 #[event]
 pub struct ProposalCreated {
     pub maker: Pubkey,
     pub proposal_address: Pubkey,
     pub proposal_index: u64,
     pub start_time: i64,
+    pub backing_ends_at: i64,
     pub token_name: String,
     pub token_symbol: String,
     pub token_uri: String,
@@ -14,6 +25,7 @@ pub struct ProposalCreated {
     pub token_vault: Pubkey,
     pub maker_account: Pubkey,
     pub proposal_bump: u8,
+    pub launch_template: Option<u64>, // None when the proposal uses the global config
 }
 
 
@@ -32,6 +44,7 @@ pub struct BackerRefunded {
     pub proposal_address: Pubkey,
     pub refund_amount: u64, // Amount sent to backer
     pub wewe_fee: u64,      // Fee collected by WEWE platform
+    pub cause: RejectionCause,
 }
 
 #[event]
@@ -41,6 +54,41 @@ pub struct ProposalRejected {
     pub proposal_address: Pubkey,
 }
 
+#[event]
+pub struct ProposalCancelled {
+    pub maker: Pubkey,
+    pub mint_account: Pubkey,
+    pub proposal_address: Pubkey,
+    pub reason: u8,
+}
+
+#[event]
+pub struct ProposalCompleted {
+    pub maker: Pubkey,
+    pub mint_account: Pubkey,
+    pub proposal_address: Pubkey,
+    pub milestones: u8,
+}
+
+#[event]
+pub struct ProposalClosed {
+    pub proposal_address: Pubkey,
+    pub maker: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+#[event]
+pub struct ProposalFinalized {
+    pub proposal_address: Pubkey,
+    pub maker: Pubkey,
+    pub mint_account: Pubkey,
+    pub succeeded: bool, // true = ready to launch, false = rejected and refundable
+    pub total_backers: u64,
+    pub total_backing: u64,
+    pub effective_backing: u64, // What the launch uses; below total_backing when oversubscribed
+    pub finalized_by: Pubkey,
+}
+
 #[event]
 pub struct AirdropClaimed {
     pub proposal_address: Pubkey,
@@ -67,15 +115,31 @@ pub struct CoinLaunched {
     pub position_nft_account: Pubkey,
     pub sqrt_price: u128,
     pub liquidity: u128,
+    pub pool_fee_schedule: PoolFeeSchedule,
+    pub activation_type: ActivationType,
+    pub activation_point: Option<u64>, // None when trading opened at creation
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub base_deposited: u64,
+    pub quote_deposited: u64,
+    pub residual_base: u64,
+    pub residual_quote: u64, // Unwrapped to SOL
+    pub residual_policy: ResidualPolicy,
+    pub residual_quote_policy: ResidualQuotePolicy,
 }
 
 #[event]
 pub struct PositionFeeClaimed {
     pub proposal: Pubkey,
     pub maker: Pubkey,
+    pub treasury: Pubkey,
     pub user: Pubkey,
-    pub user_token_amount: u64,
-    pub user_wsol_amount: u64,
+    pub maker_token_amount: u64,
+    pub maker_wsol_amount: u64,
+    pub treasury_token_amount: u64,
+    pub treasury_wsol_amount: u64,
+    pub backer_token_amount: u64,
+    pub backer_wsol_amount: u64,
     pub token_mint: Pubkey,
     pub wsol_mint: Pubkey,
 }
@@ -100,4 +164,95 @@ pub struct MilestoneEnded {
     pub proposal: Pubkey,
     pub cycle: u8,
     pub burned_units: u64,
+    pub dust_units: u64,
+}
+
+#[event]
+pub struct RoleTransferProposed {
+    pub role: AdminRole,
+    pub current_holder: Pubkey,
+    pub pending_holder: Pubkey,
+    pub proposed_by: Pubkey,
+}
+
+#[event]
+pub struct RoleTransferred {
+    pub role: AdminRole,
+    pub previous_holder: Pubkey,
+    pub new_holder: Pubkey,
+}
+
+#[event]
+pub struct ProgramPauseToggled {
+    pub paused: bool,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ProposalFreezeToggled {
+    pub proposal_address: Pubkey,
+    pub frozen: bool,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct BackerFeesClaimed {
+    pub backer: Pubkey,
+    pub proposal: Pubkey,
+    pub token_amount: u64,
+    pub wsol_amount: u64,
+}
+
+#[event]
+pub struct ConfigUpdateQueued {
+    pub update_id: u64,
+    pub param: ConfigParam,
+    pub executable_at: i64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub config_version: u64,
+    pub update_id: u64,
+    pub old_value: ConfigParam,
+    pub new_value: ConfigParam,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ConfigUpdateCancelled {
+    pub update_id: u64,
+    pub param: ConfigParam,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct LaunchTemplateCreated {
+    pub template_id: u64,
+    pub name: String,
+    pub terms: ProposalTerms,
+    pub allocation_mode: AllocationMode,
+    pub pool_fee_split: Option<PoolFeeSplit>,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct LaunchTemplateRetired {
+    pub template_id: u64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ExcessClaimed {
+    pub backer: Pubkey,
+    pub proposal_address: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BackerFeeVaultClosed {
+    pub proposal_address: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
 }
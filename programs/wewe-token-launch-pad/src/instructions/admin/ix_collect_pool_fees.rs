@@ -7,10 +7,14 @@ use anchor_spl::{
 
 use crate::{
     const_pda::{self, const_authority::VAULT_BUMP},
-    constant::{seeds::VAULT_AUTHORITY, seeds::TOKEN_VAULT, treasury},
+    constant::{
+        seeds::{ADMIN_REGISTRY, BACKER_FEE_VAULT, TOKEN_VAULT, VAULT_AUTHORITY},
+        wsol_pubkey,
+    },
     errors::ProposalError,
     event::PositionFeeClaimed,
-    state::proposal::Proposal,
+    state::{admin_registry::AdminRegistry, config::Configs, proposal::Proposal},
+    utils::{backer_fee::accrue_fee_index, pause::check_not_paused, pool_fee::split_pool_fee},
 };
 
 #[derive(Accounts)]
@@ -25,12 +29,24 @@ pub struct ClaimPositionFee<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Box<Account<'info, Configs>>,
+
     /// CHECK: maker of the propposal
     #[account(constraint = maker.key() == proposal.maker @ ProposalError::NotOwner)]
     pub maker: UncheckedAccount<'info>,
 
-    /// CHECK: owner of the propposal
-    #[account(address = treasury::ID)]
+    /// CHECK: treasury configured by the treasury manager
+    #[account(address = config.treasury @ ProposalError::IncorrectAccount)]
     pub wewe_treasury: UncheckedAccount<'info>,
 
     #[account(mut)]
@@ -46,10 +62,12 @@ pub struct ClaimPositionFee<'info> {
     )]
     pub vault_authority: SystemAccount<'info>,
 
-    /// CHECK:
+    /// CHECK: launched token mint
+    #[account(address = proposal.mint_account @ ProposalError::IncorrectAccount)]
     pub token_a_mint: UncheckedAccount<'info>,
 
-    /// CHECK:
+    /// CHECK: quote token mint (WSOL)
+    #[account(address = wsol_pubkey::ID @ ProposalError::IncorrectAccount)]
     pub token_b_mint: UncheckedAccount<'info>,
 
     /// WSOL account - can be owned by treasury or vault_authority (we'll use as temp account)
@@ -86,11 +104,15 @@ pub struct ClaimPositionFee<'info> {
     )]
     pub maker_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CHECK: pool address
+    /// CHECK: pool address, recorded on the proposal at launch
+    #[account(constraint = proposal.pool_address == Some(pool.key()) @ ProposalError::IncorrectAccount)]
     pub pool: UncheckedAccount<'info>,
 
-    /// CHECK: position address
-    #[account(mut)]
+    /// CHECK: position address, recorded on the proposal at launch
+    #[account(
+        mut,
+        constraint = proposal.pool_position == Some(position.key()) @ ProposalError::IncorrectAccount
+    )]
     pub position: UncheckedAccount<'info>,
 
     /// The user token a account - vault for token A
@@ -109,6 +131,19 @@ pub struct ClaimPositionFee<'info> {
     )]
     pub token_b_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// Per-proposal WSOL account holding the backer share; the WSOL vault is shared by every proposal.
+    /// Left out once every backer account is closed and the vault with it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [BACKER_FEE_VAULT, proposal.key().as_ref()],
+        bump,
+        token::mint = token_b_mint,
+        token::authority = vault_authority,
+        token::token_program = token_b_program,
+    )]
+    pub backer_fee_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     /// The vault token account for input token
     #[account(mut)]
     pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
@@ -175,6 +210,8 @@ pub struct ClaimPositionFee<'info> {
 
 impl<'info> ClaimPositionFee<'info> {
     pub fn handle_claim_position_fee(&mut self) -> Result<()> {
+        check_not_paused(&self.config, &self.proposal)?;
+
         // Access control: The maker account is already validated in Accounts struct
         // Anyone can call this function as long as they provide the correct maker account
         // The maker constraint ensures only the correct maker can be specified
@@ -217,15 +254,68 @@ impl<'info> ClaimPositionFee<'info> {
             return Ok(());
         }
 
-        #[inline]
-        fn split_even(amount: u64) -> (u64, u64) {
-            let half = amount / 2;
-            let remainder = amount % 2;
-            (half + remainder, half)
+        let split = self
+            .proposal
+            .effective_pool_fee_split(self.config.pool_fee_split);
+        let (maker_a, mut treasury_a, mut backer_a) = split_pool_fee(claimed_token_a, &split)?;
+        let (maker_b, mut treasury_b, mut backer_b) = split_pool_fee(claimed_token_b, &split)?;
+
+        // Once every backer account is closed nobody is left to claim the backer share
+        let open_backing = self.proposal.open_backing();
+        if open_backing == 0 {
+            require!(
+                self.backer_fee_vault.is_none(),
+                ProposalError::IncorrectAccount
+            );
+            treasury_a = treasury_a
+                .checked_add(backer_a)
+                .ok_or(ProposalError::NumericalOverflow)?;
+            treasury_b = treasury_b
+                .checked_add(backer_b)
+                .ok_or(ProposalError::NumericalOverflow)?;
+            backer_a = 0;
+            backer_b = 0;
         }
 
-        let (treasury_a, maker_a) = split_even(claimed_token_a);
-        let (treasury_b, maker_b) = split_even(claimed_token_b);
+        // The backer share is tracked on the proposal and backers pull it pro rata to their
+        // deposit through claim_backer_fees. Token fees stay in this proposal's own token vault;
+        // WSOL fees move out of the shared WSOL vault into the proposal's backer fee vault.
+        // Closed backer accounts can no longer claim, so the share is spread over the open ones
+        if backer_a > 0 || backer_b > 0 {
+            self.proposal.backer_fee_index_token =
+                accrue_fee_index(self.proposal.backer_fee_index_token, backer_a, open_backing)?;
+            self.proposal.backer_fee_index_wsol =
+                accrue_fee_index(self.proposal.backer_fee_index_wsol, backer_b, open_backing)?;
+        }
+        self.proposal.backer_fee_pool_token = self
+            .proposal
+            .backer_fee_pool_token
+            .checked_add(backer_a)
+            .ok_or(ProposalError::NumericalOverflow)?;
+        self.proposal.backer_fee_pool_wsol = self
+            .proposal
+            .backer_fee_pool_wsol
+            .checked_add(backer_b)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
+        if backer_b > 0 {
+            let backer_fee_vault = self
+                .backer_fee_vault
+                .as_ref()
+                .ok_or(ProposalError::IncorrectAccount)?;
+            anchor_spl::token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_b_program.to_account_info(),
+                    anchor_spl::token::Transfer {
+                        from: self.token_b_account.to_account_info(),
+                        to: backer_fee_vault.to_account_info(),
+                        authority: self.vault_authority.to_account_info(),
+                    },
+                    &[&vault_authority_seeds[..]],
+                ),
+                backer_b,
+            )?;
+        }
 
         if treasury_a > 0 {
             anchor_spl::token::transfer(
@@ -347,9 +437,14 @@ impl<'info> ClaimPositionFee<'info> {
         emit!(PositionFeeClaimed {
             proposal: self.proposal.key(),
             maker: self.maker.key(),
+            treasury: self.wewe_treasury.key(),
             user: self.payer.key(),
-            user_token_amount: claimed_token_a,
-            user_wsol_amount: claimed_token_b,
+            maker_token_amount: maker_a,
+            maker_wsol_amount: maker_b,
+            treasury_token_amount: treasury_a,
+            treasury_wsol_amount: treasury_b,
+            backer_token_amount: backer_a,
+            backer_wsol_amount: backer_b,
             token_mint: self.token_a_mint.key(),
             wsol_mint: self.token_b_mint.key(),
         });
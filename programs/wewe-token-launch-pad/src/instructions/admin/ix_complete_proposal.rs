@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::seeds::ADMIN_REGISTRY,
+    event::ProposalCompleted,
+    state::{
+        admin_registry::AdminRegistry,
+        proposal::{Proposal, ProposalStatus},
+    },
+};
+
+#[derive(Accounts)]
+pub struct CompleteProposal<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> CompleteProposal<'info> {
+    pub fn handle_complete_proposal(&mut self) -> Result<()> {
+        self.proposal.transition(ProposalStatus::Completed)?;
+
+        emit!(ProposalCompleted {
+            maker: self.proposal.maker,
+            proposal_address: self.proposal.key(),
+            mint_account: self.proposal.mint_account,
+            milestones: self.proposal.current_airdrop_cycle,
+        });
+
+        Ok(())
+    }
+}
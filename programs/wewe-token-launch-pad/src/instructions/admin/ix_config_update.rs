@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::seeds::{ADMIN_REGISTRY, CONFIG_UPDATE},
+    errors::ProposalError,
+    event::{ConfigUpdateCancelled, ConfigUpdateQueued, ConfigUpdated},
+    state::{
+        admin_registry::AdminRegistry,
+        config::Configs,
+        config_update::{ConfigParam, PendingConfigUpdate},
+    },
+};
+
+#[derive(Accounts)]
+pub struct QueueConfigUpdate<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Configs>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingConfigUpdate::INIT_SPACE,
+        seeds = [CONFIG_UPDATE, &config.next_config_update_id.to_le_bytes()],
+        bump,
+    )]
+    pub pending_update: Account<'info, PendingConfigUpdate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> QueueConfigUpdate<'info> {
+    pub fn handle_queue_config_update(
+        &mut self,
+        param: ConfigParam,
+        bumps: &QueueConfigUpdateBumps,
+    ) -> Result<()> {
+        // Reject changes that could never be applied to the config as it stands
+        let mut preview = (*self.config).clone();
+        preview.apply(param);
+        require!(preview.is_valid(), ProposalError::InvalidConfig);
+
+        let update_id = self.config.next_config_update_id;
+        let queued_at = Clock::get()?.unix_timestamp;
+        let executable_at = queued_at
+            .checked_add(self.config.config_update_delay)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
+        self.pending_update.set_inner(PendingConfigUpdate {
+            update_id,
+            param,
+            queued_by: self.authority.key(),
+            queued_at,
+            executable_at,
+            bump: bumps.pending_update,
+        });
+        self.config.next_config_update_id = update_id
+            .checked_add(1)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
+        emit!(ConfigUpdateQueued {
+            update_id,
+            param,
+            executable_at,
+            authority: self.authority.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ExecuteConfigUpdate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Configs>,
+
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [CONFIG_UPDATE, &pending_update.update_id.to_le_bytes()],
+        bump = pending_update.bump,
+    )]
+    pub pending_update: Account<'info, PendingConfigUpdate>,
+
+    /// CHECK: receives the pending update's rent
+    #[account(mut, address = pending_update.queued_by @ ProposalError::IncorrectAccount)]
+    pub queued_by: UncheckedAccount<'info>,
+}
+
+impl<'info> ExecuteConfigUpdate<'info> {
+    pub fn handle_execute_config_update(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= self.pending_update.executable_at,
+            ProposalError::ConfigUpdateNotReady
+        );
+
+        let new_value = self.pending_update.param;
+        let old_value = self.config.current_value(&new_value);
+
+        // Updates applied while this one waited may have made it invalid
+        self.config.apply(new_value);
+        require!(self.config.is_valid(), ProposalError::InvalidConfig);
+
+        self.config.config_version = self
+            .config
+            .config_version
+            .checked_add(1)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
+        emit!(ConfigUpdated {
+            config_version: self.config.config_version,
+            update_id: self.pending_update.update_id,
+            old_value,
+            new_value,
+            authority: self.authority.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CancelConfigUpdate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [CONFIG_UPDATE, &pending_update.update_id.to_le_bytes()],
+        bump = pending_update.bump,
+    )]
+    pub pending_update: Account<'info, PendingConfigUpdate>,
+
+    /// CHECK: receives the pending update's rent
+    #[account(mut, address = pending_update.queued_by @ ProposalError::IncorrectAccount)]
+    pub queued_by: UncheckedAccount<'info>,
+}
+
+impl<'info> CancelConfigUpdate<'info> {
+    pub fn handle_cancel_config_update(&mut self) -> Result<()> {
+        emit!(ConfigUpdateCancelled {
+            update_id: self.pending_update.update_id,
+            param: self.pending_update.param,
+            authority: self.authority.key(),
+        });
+
+        Ok(())
+    }
+}
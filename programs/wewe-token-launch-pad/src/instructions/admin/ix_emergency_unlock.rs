@@ -1,18 +1,27 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constant::chain_service_pubkey,
+    constant::seeds::ADMIN_REGISTRY,
     errors::ProposalError,
-    state::proposal::Proposal,
+    state::{
+        admin_registry::AdminRegistry,
+        proposal::{Proposal, ProposalStatus},
+    },
 };
 
 #[derive(Accounts)]
 pub struct EmergencyUnlock<'info> {
     /// CHECK: Admin authority
     #[account(
-        constraint = authority.key() == chain_service_pubkey::ID @ ProposalError::UnauthorizedEmergencyUnlock
+        constraint = authority.key() == admin_registry.operator @ ProposalError::UnauthorizedEmergencyUnlock
     )]
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
     
     #[account(mut)]
     pub proposal: Account<'info, Proposal>,
@@ -25,7 +34,7 @@ impl<'info> EmergencyUnlock<'info> {
     pub fn handle_emergency_unlock(&mut self) -> Result<()> {
         // Require that pool launch flag is set (otherwise no need to unlock)
         require!(
-            self.proposal.is_pool_launched,
+            self.proposal.is_launched(),
             ProposalError::PoolAlreadyLaunched
         );
 
@@ -42,7 +51,7 @@ impl<'info> EmergencyUnlock<'info> {
         
         // Option 1: Pool doesn't exist - allow unlock
         if !pool_exists {
-            self.proposal.is_pool_launched = false;
+            self.proposal.transition(ProposalStatus::Completed)?;
             self.proposal.emergency_unlocked = true;
             return Ok(());
         }
@@ -58,7 +67,7 @@ impl<'info> EmergencyUnlock<'info> {
             
             // Even if pool exists, if enough time has passed and admin confirms,
             // allow unlock (admin can verify pool state manually)
-            self.proposal.is_pool_launched = false;
+            self.proposal.transition(ProposalStatus::Completed)?;
             self.proposal.emergency_unlocked = true;
             return Ok(());
         }
@@ -1,9 +1,12 @@
 use crate::{
     const_pda::const_authority::VAULT_BUMP,
-    constant::seeds::{TOKEN_VAULT, VAULT_AUTHORITY},
+    constant::seeds::{ADMIN_REGISTRY, TOKEN_VAULT, VAULT_AUTHORITY},
     errors::ProposalError,
     event::MilestoneEnded,
-    state::proposal::Proposal,
+    state::{
+        admin_registry::AdminRegistry,
+        proposal::{Proposal, ProposalStatus},
+    },
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
@@ -11,6 +14,12 @@ use anchor_spl::token::{Mint, Token, TokenAccount};
 #[derive(Accounts)]
 pub struct EndMilestone<'info> {
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
     #[account(mut)]
     pub proposal: Account<'info, Proposal>,
 
@@ -41,10 +50,8 @@ pub struct EndMilestone<'info> {
 
 impl<'info> EndMilestone<'info> {
     pub fn handle_end_milestone(&mut self) -> Result<()> {
-        require!(self.proposal.is_pool_launched, ProposalError::TargetNotMet);
-        require!(!self.proposal.is_rejected, ProposalError::ProposalRejected);
         require!(
-            self.proposal.milestone_active,
+            self.proposal.status == ProposalStatus::MilestoneActive,
             ProposalError::NoMilestoneActive
         );
         require!(
@@ -65,10 +72,21 @@ impl<'info> EndMilestone<'info> {
             .ok_or(ProposalError::NumericalOverflow)?;
         let burn_amount_base = max_possible_reputation
             .saturating_sub(self.proposal.milestone_reputation_sum);
-        
+
+        // Rounding dust: the part of the milestone pool no backer share covers,
+        // plus whatever launch airdrop dust was recorded since the last milestone
+        let dust = self
+            .proposal
+            .terms
+            .total_airdrop_amount_per_milestone
+            .saturating_sub(self.proposal.milestone_share_sum)
+            .checked_add(self.proposal.rounding_dust)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
         // Convert from base units to token units (with decimals)
         let burn_amount = burn_amount_base
-            .checked_mul(pow)
+            .checked_add(dust)
+            .and_then(|n| n.checked_mul(pow))
             .ok_or(ProposalError::NumericalOverflow)?;
 
         anchor_spl::token::burn(
@@ -84,15 +102,17 @@ impl<'info> EndMilestone<'info> {
             burn_amount,
         )?;
 
-        self.proposal.milestone_active = false;
+        self.proposal.transition(ProposalStatus::Launched)?;
         self.proposal.milestone_reputation_sum = 0;
+        self.proposal.rounding_dust = 0;
         self.proposal.current_airdrop_cycle = self.proposal.current_airdrop_cycle.checked_add(1)
         .ok_or(ProposalError::NumericalOverflow)?;
 
         emit!(MilestoneEnded {
             proposal: self.proposal.key(),
             cycle: self.proposal.current_airdrop_cycle,
-            burned_units: burn_amount
+            burned_units: burn_amount,
+            dust_units: dust,
         });
         Ok(())
     }
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::{configure_authority_pubkey, seeds::ADMIN_REGISTRY, ANCHOR_DISCRIMINATOR},
+    errors::ProposalError,
+    state::admin_registry::AdminRegistry,
+};
+
+#[derive(Accounts)]
+pub struct InitAdminRegistry<'info> {
+    /// Bootstrap key; becomes the first configurator
+    #[account(
+        mut,
+        address = configure_authority_pubkey::ID @ ProposalError::NotOwner,
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + AdminRegistry::INIT_SPACE,
+        seeds = [ADMIN_REGISTRY],
+        bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitAdminRegistry<'info> {
+    pub fn handle_init_admin_registry(
+        &mut self,
+        operator: Pubkey,
+        pauser: Pubkey,
+        treasury_manager: Pubkey,
+        bumps: &InitAdminRegistryBumps,
+    ) -> Result<()> {
+        self.admin_registry.set_inner(AdminRegistry {
+            configurator: self.authority.key(),
+            operator,
+            pauser,
+            treasury_manager,
+            pending_configurator: None,
+            pending_operator: None,
+            pending_pauser: None,
+            pending_treasury_manager: None,
+            bump: bumps.admin_registry,
+        });
+
+        Ok(())
+    }
+}
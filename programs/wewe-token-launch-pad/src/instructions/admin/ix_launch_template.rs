@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::seeds::{ADMIN_REGISTRY, LAUNCH_TEMPLATE},
+    errors::ProposalError,
+    event::{LaunchTemplateCreated, LaunchTemplateRetired},
+    state::{
+        admin_registry::AdminRegistry,
+        config::{AllocationMode, Configs, PoolFeeSplit, ProposalTerms},
+        launch_template::LaunchTemplate,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CreateLaunchTemplate<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Configs>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LaunchTemplate::INIT_SPACE,
+        seeds = [LAUNCH_TEMPLATE, &config.launch_template_count.to_le_bytes()],
+        bump,
+    )]
+    pub launch_template: Account<'info, LaunchTemplate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateLaunchTemplate<'info> {
+    pub fn handle_create_launch_template(
+        &mut self,
+        name: String,
+        terms: ProposalTerms,
+        allocation_mode: AllocationMode,
+        pool_fee_split: Option<PoolFeeSplit>,
+        bumps: &CreateLaunchTemplateBumps,
+    ) -> Result<()> {
+        require!(name.len() <= 32, ProposalError::LenthTooLong);
+        require!(terms.is_valid(), ProposalError::InvalidConfig);
+        if let Some(split) = pool_fee_split {
+            require!(
+                split.is_valid_override_of(&self.config.pool_fee_split),
+                ProposalError::InvalidFeeSplit
+            );
+        }
+
+        let template_id = self.config.launch_template_count;
+        self.launch_template.set_inner(LaunchTemplate {
+            template_id,
+            name: name.clone(),
+            terms,
+            allocation_mode,
+            pool_fee_split,
+            retired: false,
+            bump: bumps.launch_template,
+        });
+        self.config.launch_template_count = template_id
+            .checked_add(1)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
+        emit!(LaunchTemplateCreated {
+            template_id,
+            name,
+            terms,
+            allocation_mode,
+            pool_fee_split,
+            authority: self.authority.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RetireLaunchTemplate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [LAUNCH_TEMPLATE, &launch_template.template_id.to_le_bytes()],
+        bump = launch_template.bump,
+    )]
+    pub launch_template: Account<'info, LaunchTemplate>,
+}
+
+impl<'info> RetireLaunchTemplate<'info> {
+    pub fn handle_retire_launch_template(&mut self) -> Result<()> {
+        require!(
+            !self.launch_template.retired,
+            ProposalError::LaunchTemplateRetired
+        );
+
+        // Proposals already created from it keep their snapshot of the terms
+        self.launch_template.retired = true;
+
+        emit!(LaunchTemplateRetired {
+            template_id: self.launch_template.template_id,
+            authority: self.authority.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+
+use crate::{
+    constant::{
+        seeds::ADMIN_REGISTRY, treasury, ANCHOR_DISCRIMINATOR, LEGACY_BACKING_WINDOW,
+        MAXIMUM_BACKERS,
+    },
+    errors::ProposalError,
+    state::{
+        admin_registry::AdminRegistry,
+        config::{
+            AllocationMode, Configs, PoolActivation, PoolFeeSchedule, PoolFeeSplit, PoolPriceRange,
+            ProposalTerms, RefundFeeSchedule, ResidualPolicy, ResidualQuotePolicy,
+        },
+    },
+};
+
+/// `Configs` layout before per-user min/max amounts and everything added after them.
+#[derive(AnchorDeserialize, InitSpace)]
+struct LegacyConfigs {
+    amount_to_raise_per_user: u64,
+    total_mint: u64,
+    total_pool_tokens: u64,
+    maker_token_amount: u64,
+    total_airdrop_amount_per_milestone: u64,
+    min_backers: u64,
+    max_backed_proposals: u64,
+    refund_fee_basis_points: u16,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    /// CHECK: legacy config account, layout is checked in the handler
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        owner = crate::ID @ ProposalError::IncorrectAccount,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateConfig<'info> {
+    pub fn handle_migrate_config(
+        &mut self,
+        sqrt_price_tolerance_bps: u16,
+        config_update_delay: i64,
+        terms: Option<ProposalTerms>,
+    ) -> Result<()> {
+        let info = self.config.to_account_info();
+        require!(
+            info.data_len() == ANCHOR_DISCRIMINATOR + LegacyConfigs::INIT_SPACE,
+            ProposalError::ConfigAlreadyMigrated
+        );
+
+        let legacy = {
+            let data = info.try_borrow_data()?;
+            require!(
+                data[..ANCHOR_DISCRIMINATOR] == *Configs::DISCRIMINATOR,
+                ProposalError::IncorrectAccount
+            );
+            LegacyConfigs::deserialize(&mut &data[ANCHOR_DISCRIMINATOR..])?
+        };
+
+        // Fields without a legacy counterpart take the behaviour the program had before them
+        let mut migrated = Configs {
+            // Legacy backers all paid the same fixed amount
+            min_amount_per_user: legacy.amount_to_raise_per_user,
+            max_amount_per_user: legacy.amount_to_raise_per_user,
+            total_mint: legacy.total_mint,
+            total_pool_tokens: legacy.total_pool_tokens,
+            maker_token_amount: legacy.maker_token_amount,
+            total_airdrop_amount_per_milestone: legacy.total_airdrop_amount_per_milestone,
+            min_backers: legacy.min_backers,
+            max_backed_proposals: legacy.max_backed_proposals,
+            // The single legacy fee applied to every refund; maker cancellations are fee-free
+            refund_fees: RefundFeeSchedule {
+                backer_exit_bps: legacy.refund_fee_basis_points,
+                platform_rejected_bps: legacy.refund_fee_basis_points,
+                failed_raise_bps: legacy.refund_fee_basis_points,
+                maker_cancelled_bps: 0,
+            },
+            allocation_mode: AllocationMode::Equal,
+            min_backing_duration: LEGACY_BACKING_WINDOW,
+            max_backing_duration: LEGACY_BACKING_WINDOW,
+            withdrawal_lockout_seconds: 0,
+            sqrt_price_tolerance_bps,
+            paused: false,
+            treasury: treasury::ID,
+            pool_fee_split: PoolFeeSplit::DEFAULT,
+            pool_fee_schedule: PoolFeeSchedule::DEFAULT,
+            pool_activation: PoolActivation::DEFAULT,
+            pool_price_range: PoolPriceRange::DEFAULT,
+            residual_policy: ResidualPolicy::Burn,
+            residual_quote_policy: ResidualQuotePolicy::Treasury,
+            config_version: 0,
+            config_update_delay,
+            next_config_update_id: 0,
+            launch_template_count: 0,
+            max_backers: MAXIMUM_BACKERS,
+            soft_cap: 0,
+            hard_cap: 0,
+            oversubscription: false,
+        };
+        // A legacy config that breaks a current invariant cannot be fixed one queued update at a
+        // time, since every update is checked against the whole config; take corrected terms here
+        if let Some(terms) = terms {
+            migrated.set_proposal_terms(terms);
+        }
+        require!(migrated.is_valid(), ProposalError::InvalidConfig);
+
+        // Grow the account to the current layout, topping up rent from the authority
+        let new_len = ANCHOR_DISCRIMINATOR + Configs::INIT_SPACE;
+        let rent_due = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(info.lamports());
+        if rent_due > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.authority.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+        info.realloc(new_len, false)?;
+
+        let mut data = info.try_borrow_mut_data()?;
+        migrated.try_serialize(&mut &mut data[..])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+
+use crate::{
+    const_pda::const_authority::VAULT_BUMP,
+    constant::{
+        seeds::{ADMIN_REGISTRY, PROPOSAL_ESCROW, VAULT_AUTHORITY},
+        ANCHOR_DISCRIMINATOR, LEGACY_BACKING_WINDOW,
+    },
+    errors::ProposalError,
+    state::{
+        admin_registry::AdminRegistry,
+        config::{AllocationMode, Configs},
+        proposal::{Proposal, ProposalStatus, RejectionCause},
+    },
+    utils::{backer_allocation::get_backer_allocation, escrow::check_escrow_balance},
+};
+
+/// `Proposal` layout before the lifecycle flags were folded into `ProposalStatus`.
+#[derive(AnchorDeserialize, InitSpace)]
+struct LegacyProposal {
+    maker: Pubkey,
+    mint_account: Pubkey,
+    time_started: i64,
+    bump: u8,
+    is_rejected: bool,
+    proposal_id: u64,
+    is_pool_launched: bool,
+    total_backers: u64,
+    total_backing: u64,
+    current_airdrop_cycle: u8,
+    milestone_active: bool,
+    milestone_units_assigned: u64,
+    milestone_backers_weighted: u64,
+    milestone_reputation_sum: u64,
+    launch_timestamp: Option<i64>,
+    emergency_unlocked: bool,
+    #[max_len(32)]
+    token_name: String,
+    #[max_len(10)]
+    token_symbol: String,
+    #[max_len(200)]
+    token_uri: String,
+}
+
+#[derive(Accounts)]
+pub struct MigrateProposal<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: legacy proposal account, layout is checked in the handler
+    #[account(mut, owner = crate::ID @ ProposalError::IncorrectAccount)]
+    pub proposal: UncheckedAccount<'info>,
+
+    /// CHECK: SOL escrow that takes over this proposal's backing
+    #[account(
+        mut,
+        seeds = [PROPOSAL_ESCROW, proposal.key().as_ref()],
+        bump,
+    )]
+    pub proposal_escrow: SystemAccount<'info>,
+
+    /// CHECK: vault authority that held the backing of every legacy proposal
+    #[account(
+        mut,
+        seeds = [VAULT_AUTHORITY.as_ref()],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Configs>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateProposal<'info> {
+    pub fn handle_migrate_proposal(
+        &mut self,
+        pool_address: Option<Pubkey>,
+        pool_position: Option<Pubkey>,
+    ) -> Result<()> {
+        let info = self.proposal.to_account_info();
+        require!(
+            info.data_len() == ANCHOR_DISCRIMINATOR + LegacyProposal::INIT_SPACE,
+            ProposalError::ProposalAlreadyMigrated
+        );
+
+        let legacy = {
+            let data = info.try_borrow_data()?;
+            require!(
+                data[..ANCHOR_DISCRIMINATOR] == *Proposal::DISCRIMINATOR,
+                ProposalError::IncorrectAccount
+            );
+            LegacyProposal::deserialize(&mut &data[ANCHOR_DISCRIMINATOR..])?
+        };
+
+        // The legacy layout never recorded the pool, so a launched proposal needs it passed in
+        require!(
+            pool_address.is_some() == legacy.is_pool_launched
+                && pool_position.is_some() == legacy.is_pool_launched,
+            ProposalError::IncorrectAccount
+        );
+
+        // Legacy proposals were always read against the live config
+        let terms = self.config.proposal_terms();
+
+        let status = if legacy.emergency_unlocked {
+            ProposalStatus::Completed
+        } else if legacy.is_rejected {
+            ProposalStatus::Rejected
+        } else if legacy.milestone_active {
+            ProposalStatus::MilestoneActive
+        } else if legacy.is_pool_launched {
+            ProposalStatus::Launched
+        } else {
+            ProposalStatus::Backing
+        };
+
+        // Snapshots taken in a running milestone each covered an equal share of its pool
+        let milestone_share_sum = if legacy.milestone_active && legacy.total_backers > 0 {
+            get_backer_allocation(
+                AllocationMode::Equal,
+                terms.total_airdrop_amount_per_milestone,
+                0,
+                legacy.total_backing,
+                legacy.total_backers,
+            )?
+            .checked_mul(legacy.milestone_backers_weighted)
+            .ok_or(ProposalError::NumericalOverflow)?
+        } else {
+            0
+        };
+
+        let migrated = Proposal {
+            maker: legacy.maker,
+            mint_account: legacy.mint_account,
+            time_started: legacy.time_started,
+            backing_ends_at: legacy.time_started.saturating_add(LEGACY_BACKING_WINDOW),
+            bump: legacy.bump,
+            status,
+            // Legacy rejections could only come from reject_proposal
+            rejection_cause: legacy
+                .is_rejected
+                .then_some(RejectionCause::PlatformRejected),
+            proposal_id: legacy.proposal_id,
+            total_backers: legacy.total_backers,
+            total_backing: legacy.total_backing,
+            current_airdrop_cycle: legacy.current_airdrop_cycle,
+            milestone_units_assigned: legacy.milestone_units_assigned,
+            milestone_backers_weighted: legacy.milestone_backers_weighted,
+            milestone_reputation_sum: legacy.milestone_reputation_sum,
+            // Legacy backers all paid the same fixed amount
+            allocation_mode: AllocationMode::Equal,
+            milestone_share_sum,
+            // Airdrops paid before the migration did not record their dust; it stays in the vault
+            rounding_dust: 0,
+            airdrop_dust_remainder: 0,
+            launch_timestamp: legacy.launch_timestamp,
+            emergency_unlocked: legacy.emergency_unlocked,
+            // The original payer is not recorded in the legacy layout
+            rent_payer: self.payer.key(),
+            closed_backers: 0,
+            closed_backing: 0,
+            frozen: false,
+            pool_fee_split: None,
+            backer_fee_pool_token: 0,
+            backer_fee_pool_wsol: 0,
+            backer_fee_index_token: 0,
+            backer_fee_index_wsol: 0,
+            pool_address,
+            pool_position,
+            pool_fee_schedule: None,
+            pool_activation_point: None,
+            pool_sqrt_min_price: 0,
+            pool_sqrt_max_price: 0,
+            residual_base: 0,
+            residual_quote: 0,
+            residual_policy: None,
+            residual_quote_policy: None,
+            terms,
+            launch_terms: self.config.pool_launch_terms(),
+            launch_template: None,
+            excess_refunded: 0,
+            token_name: legacy.token_name,
+            token_symbol: legacy.token_symbol,
+            token_uri: legacy.token_uri,
+        };
+
+        // Grow the account to the current layout, topping up rent from the payer
+        let new_len = ANCHOR_DISCRIMINATOR + Proposal::INIT_SPACE;
+        let rent = Rent::get()?;
+        let rent_due = rent
+            .minimum_balance(new_len)
+            .saturating_sub(info.lamports());
+        if rent_due > 0 {
+            self.transfer_from_payer(info.clone(), rent_due)?;
+        }
+        info.realloc(new_len, false)?;
+        {
+            let mut data = info.try_borrow_mut_data()?;
+            migrated.try_serialize(&mut &mut data[..])?;
+        }
+
+        // Pre-launch backing still sits in the shared vault; move it into the proposal escrow
+        let escrow_rent = rent
+            .minimum_balance(0)
+            .saturating_sub(self.proposal_escrow.lamports());
+        if escrow_rent > 0 {
+            self.transfer_from_payer(self.proposal_escrow.to_account_info(), escrow_rent)?;
+        }
+
+        if matches!(status, ProposalStatus::Backing | ProposalStatus::Rejected)
+            && migrated.total_backing > 0
+        {
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.vault_authority.to_account_info(),
+                        to: self.proposal_escrow.to_account_info(),
+                    },
+                    &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]],
+                ),
+                migrated.total_backing,
+            )?;
+
+            check_escrow_balance(
+                &self.proposal_escrow.to_account_info(),
+                migrated.total_backing,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn transfer_from_payer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.payer.to_account_info(),
+                    to,
+                },
+            ),
+            amount,
+        )
+    }
+}
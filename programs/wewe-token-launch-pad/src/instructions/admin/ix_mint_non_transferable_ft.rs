@@ -2,14 +2,21 @@ use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
-use crate::constant::seeds::{MINT_ACCOUNT, MINT_AUTHORITY};
+use crate::constant::seeds::{ADMIN_REGISTRY, MINT_ACCOUNT, MINT_AUTHORITY};
 use crate::errors::ProposalError;
+use crate::state::admin_registry::AdminRegistry;
 
 #[derive(Accounts)]
 pub struct MintSoulboundToUser<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
     #[account(
         init_if_needed,
         payer = authority,
@@ -1,22 +1,30 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::ProposalError, event::ProposalRejected, state::proposal::Proposal};
+use crate::{
+    constant::seeds::ADMIN_REGISTRY,
+    event::ProposalRejected,
+    state::{
+        admin_registry::AdminRegistry,
+        proposal::{Proposal, RejectionCause},
+    },
+};
 
 #[derive(Accounts)]
 pub struct RejectProposal<'info> {
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
     #[account(mut)]
     pub proposal: Account<'info, Proposal>,
 }
 
 impl<'info> RejectProposal<'info> {
     pub fn handle_reject_proposal(&mut self) -> Result<()> {
-        require!(
-            !self.proposal.is_pool_launched,
-            ProposalError::PoolAlreadyLaunched
-        );
-        require!(!self.proposal.is_rejected, ProposalError::ProposalRejected);
-        self.proposal.is_rejected = true;
+        self.proposal.reject(RejectionCause::PlatformRejected)?;
 
         emit!(ProposalRejected {
             maker: self.proposal.maker,
@@ -1,12 +1,18 @@
 use crate::{
-    constant::seeds::BACKER,
-    state::{backers::Backers, proposal::Proposal},
+    constant::seeds::{ADMIN_REGISTRY, BACKER},
+    state::{admin_registry::AdminRegistry, backers::Backers, proposal::Proposal},
 };
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct ResetAirdrop<'info> {
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
     
     #[account(mut)]
     pub proposal: Account<'info, Proposal>,
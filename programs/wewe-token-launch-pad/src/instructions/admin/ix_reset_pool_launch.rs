@@ -1,20 +1,29 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{TokenAccount, TokenInterface};
-use anchor_spl::token::{Mint, Token};
+use anchor_spl::token::Mint;
 
 use crate::{
     constant::{
-        seeds::{TOKEN_VAULT, VAULT_AUTHORITY},
-        wsol_pubkey,
+        seeds::{ADMIN_REGISTRY, PROPOSAL_ESCROW, TOKEN_VAULT, VAULT_AUTHORITY},
         MINT_DECIMALS,
     },
     errors::ProposalError,
-    state::proposal::Proposal,
+    state::{
+        admin_registry::AdminRegistry,
+        proposal::{Proposal, ProposalStatus},
+    },
+    utils::escrow::check_escrow_balance,
 };
 
 #[derive(Accounts)]
 pub struct ResetPoolLaunch<'info> {
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
     
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -29,22 +38,14 @@ pub struct ResetPoolLaunch<'info> {
         bump,
     )]
     pub vault_authority: SystemAccount<'info>,
-    
+
+    /// CHECK: SOL escrow holding this proposal's backing; the operator refunds it before the reset
     #[account(
-        mut,
-        seeds = [TOKEN_VAULT, vault_authority.key().as_ref(), wsol_pubkey::ID.as_ref()],
+        seeds = [PROPOSAL_ESCROW, proposal.key().as_ref()],
         bump,
-        token::mint = quote_mint,
-        token::authority = vault_authority,
-    )]
-    pub wsol_vault: Box<InterfaceAccount<'info, TokenAccount>>,
-    
-    /// CHECK: quote token mint (WSOL)
-    #[account(
-        address = wsol_pubkey::ID @ ProposalError::IncorrectAccount
     )]
-    pub quote_mint: UncheckedAccount<'info>,
-    
+    pub proposal_escrow: SystemAccount<'info>,
+
     #[account(
         init,
         payer = payer,
@@ -74,28 +75,38 @@ impl<'info> ResetPoolLaunch<'info> {
         
         // Check that pool is currently launched
         require!(
-            self.proposal.is_pool_launched,
+            self.proposal.is_launched(),
             ProposalError::PoolNotInitialized
         );
         
-        // Check that WSOL vault has enough funds to cover the proposal's total_backing
-        require!(
-            self.wsol_vault.amount >= self.proposal.total_backing,
-            ProposalError::InsufficientFunds
-        );
+        // The WSOL vault is shared by every proposal, so the backing is never pulled back out of it.
+        // The next create_pool funds the launch from this proposal's escrow alone
+        check_escrow_balance(
+            &self.proposal_escrow.to_account_info(),
+            self.proposal.escrowed_backing(),
+        )?;
         
-        // Reset the pool launch flag
-        self.proposal.is_pool_launched = false;
-
-        self.proposal.milestone_active = false;
+        // Back to ready-to-launch
+        self.proposal.transition(ProposalStatus::Succeeded)?;
         
         // Update the mint account (use the initialized mint account's key)
         self.proposal.mint_account = self.mint_account.key();
         
         // Optionally reset launch_timestamp (set to None)
         self.proposal.launch_timestamp = None;
+
+        // Nothing recorded about the abandoned pool carries over to the next launch
+        self.proposal.pool_address = None;
+        self.proposal.pool_position = None;
+        self.proposal.pool_fee_schedule = None;
+        self.proposal.pool_activation_point = None;
+        self.proposal.pool_sqrt_min_price = 0;
+        self.proposal.pool_sqrt_max_price = 0;
+        self.proposal.residual_base = 0;
+        self.proposal.residual_quote = 0;
+        self.proposal.residual_policy = None;
+        self.proposal.residual_quote_policy = None;
         
         Ok(())
     }
 }
-
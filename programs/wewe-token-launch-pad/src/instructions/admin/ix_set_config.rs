@@ -1,11 +1,27 @@
-// set constant.rs values 
-use crate::state::config::Configs;
+// initialize the config; later changes are queued through ix_config_update
+use crate::{
+    constant::{seeds::ADMIN_REGISTRY, treasury, MAXIMUM_BACKERS},
+    errors::ProposalError,
+    state::{
+        admin_registry::AdminRegistry,
+        config::{
+            AllocationMode, Configs, PoolActivation, PoolFeeSchedule, PoolFeeSplit, PoolPriceRange,
+            RefundFeeSchedule, ResidualPolicy, ResidualQuotePolicy,
+        },
+    },
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct SetConfig<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
     
     #[account(
         init_if_needed,
@@ -22,26 +38,65 @@ pub struct SetConfig<'info> {
 impl<'info> SetConfig<'info> {
     pub fn handle_set_config(
         &mut self,
-        amount_to_raise_per_user: u64,
+        min_amount_per_user: u64,
+        max_amount_per_user: u64,
         total_mint: u64,
         total_pool_tokens: u64,
         maker_token_amount: u64,
         total_airdrop_amount_per_milestone: u64,
         min_backers: u64,
         max_backed_proposals: u64,
-        refund_fee_basis_points: u16,
+        refund_fees: RefundFeeSchedule,
+        allocation_mode: AllocationMode,
+        min_backing_duration: i64,
+        max_backing_duration: i64,
+        withdrawal_lockout_seconds: i64,
+        sqrt_price_tolerance_bps: u16,
+        config_update_delay: i64,
     ) -> Result<()> {
+        // Later changes go through queue_config_update and its timelock
+        require!(
+            self.config.treasury == Pubkey::default(),
+            ProposalError::ConfigAlreadyInitialized
+        );
+
         self.config.set_inner(Configs {
-            amount_to_raise_per_user: amount_to_raise_per_user,
+            min_amount_per_user: min_amount_per_user,
+            max_amount_per_user: max_amount_per_user,
             total_mint: total_mint,
             total_pool_tokens: total_pool_tokens,
             maker_token_amount: maker_token_amount,
             total_airdrop_amount_per_milestone: total_airdrop_amount_per_milestone,
             min_backers: min_backers,
             max_backed_proposals: max_backed_proposals,
-            refund_fee_basis_points: refund_fee_basis_points, // 100 BPS = 1%
+            refund_fees: refund_fees,
+            allocation_mode: allocation_mode,
+            min_backing_duration: min_backing_duration,
+            max_backing_duration: max_backing_duration,
+            withdrawal_lockout_seconds: withdrawal_lockout_seconds,
+            sqrt_price_tolerance_bps: sqrt_price_tolerance_bps,
+            paused: false,
+            treasury: treasury::ID,
+            pool_fee_split: PoolFeeSplit::DEFAULT,
+            pool_fee_schedule: PoolFeeSchedule::DEFAULT,
+            pool_activation: PoolActivation::DEFAULT,
+            pool_price_range: PoolPriceRange::DEFAULT,
+            // Unused base stayed locked in the token vault before the policy existed,
+            // which leaves circulating supply the same as burning it
+            residual_policy: ResidualPolicy::Burn,
+            residual_quote_policy: ResidualQuotePolicy::Treasury,
+            config_version: 0,
+            config_update_delay,
+            next_config_update_id: 0,
+            launch_template_count: 0,
+            // Caps start open; tighten them with queue_config_update
+            max_backers: MAXIMUM_BACKERS,
+            soft_cap: 0,
+            hard_cap: 0,
+            oversubscription: false,
         });
-        
+        require!(self.config.is_valid(), ProposalError::InvalidConfig);
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::seeds::ADMIN_REGISTRY,
+    event::{ProgramPauseToggled, ProposalFreezeToggled},
+    state::{admin_registry::AdminRegistry, config::Configs, proposal::Proposal},
+};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Configs>,
+}
+
+impl<'info> SetPaused<'info> {
+    pub fn handle_set_paused(&mut self, paused: bool) -> Result<()> {
+        self.config.paused = paused;
+
+        emit!(ProgramPauseToggled {
+            paused,
+            authority: self.authority.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetProposalFrozen<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> SetProposalFrozen<'info> {
+    pub fn handle_set_proposal_frozen(&mut self, frozen: bool) -> Result<()> {
+        self.proposal.frozen = frozen;
+
+        emit!(ProposalFreezeToggled {
+            proposal_address: self.proposal.key(),
+            frozen,
+            authority: self.authority.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -1,8 +1,13 @@
 use crate::{
-    constant::seeds::BACKER,
+    constant::seeds::{ADMIN_REGISTRY, BACKER},
     errors::ProposalError,
     event::BackerMilestoneSettled,
-    state::{backers::Backers, config::Configs, proposal::Proposal},
+    state::{
+        admin_registry::AdminRegistry,
+        backers::Backers,
+        proposal::{Proposal, ProposalStatus},
+    },
+    utils::backer_allocation::get_backer_allocation,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, TokenAccount};
@@ -12,6 +17,12 @@ pub struct SnapshotBacker<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+
     #[account(mut)]
     pub proposal: Account<'info, Proposal>,
 
@@ -33,14 +44,13 @@ pub struct SnapshotBacker<'info> {
         constraint = backer_token_account.owner == backer.key()      @ ProposalError::NotOwner,
     )]
     pub backer_token_account: Account<'info, TokenAccount>,
-    pub config: Account<'info, Configs>,
 }
 
 impl<'info> SnapshotBacker<'info> {
     pub fn handle_snapshot(&mut self) -> Result<()> {
-        require!(self.proposal.is_pool_launched, ProposalError::TargetNotMet);
+        require!(self.proposal.is_launched(), ProposalError::TargetNotMet);
         require!(
-            self.proposal.milestone_active,
+            self.proposal.status == ProposalStatus::MilestoneActive,
             ProposalError::NoMilestoneActive
         );
 
@@ -49,11 +59,13 @@ impl<'info> SnapshotBacker<'info> {
             cur > self.backer_account.settle_cycle,
             ProposalError::AmountAlreadyUpdated
         );
-        let per = self
-            .config
-            .total_airdrop_amount_per_milestone //TOTAL_AIRDROP_AMOUNT_PER_MILESTONE
-            .checked_div(self.proposal.total_backers)
-            .ok_or(ProposalError::NumericalOverflow)?;
+        let per = get_backer_allocation(
+            self.proposal.allocation_mode,
+            self.proposal.terms.total_airdrop_amount_per_milestone,
+            self.backer_account.deposit_amount,
+            self.proposal.total_backing,
+            self.proposal.total_backers,
+        )?;
 
         let pow = 10u64
             .checked_pow(self.mint_account.decimals as u32)
@@ -110,6 +122,12 @@ impl<'info> SnapshotBacker<'info> {
                 .ok_or(ProposalError::NumericalOverflow)?;
         }
 
+        self.proposal.milestone_share_sum = self
+            .proposal
+            .milestone_share_sum
+            .checked_add(per)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
         self.backer_account.settle_cycle = cur;
         self.proposal.milestone_backers_weighted = self
             .proposal
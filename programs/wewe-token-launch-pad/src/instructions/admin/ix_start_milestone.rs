@@ -1,8 +1,11 @@
 use crate::{
-    constant::seeds::PROPOSAL,
+    constant::seeds::{ADMIN_REGISTRY, PROPOSAL},
     errors::ProposalError,
     event::MilestoneStarted,
-    state::proposal::Proposal,
+    state::{
+        admin_registry::AdminRegistry,
+        proposal::{Proposal, ProposalStatus},
+    },
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -17,6 +20,12 @@ use anchor_spl::token::spl_token::instruction::AuthorityType;
 #[derive(Accounts)]
 pub struct InitialiseMilestone<'info> {
     pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
     #[account(mut)]
     pub proposal: Account<'info, Proposal>,
     /// CHECK: Mint account from proposal
@@ -43,12 +52,7 @@ pub struct InitialiseMilestone<'info> {
 
 impl<'info> InitialiseMilestone<'info> {
     pub fn handle_initialise_milestone(&mut self) -> Result<()> {
-        require!(!self.proposal.is_rejected, ProposalError::ProposalRejected);
-        require!(self.proposal.is_pool_launched, ProposalError::TargetNotMet);
-        require!(
-            !self.proposal.milestone_active,
-            ProposalError::NoMilestoneActive
-        );
+        self.proposal.transition(ProposalStatus::MilestoneActive)?;
 
         let proposal_signer_seeds: &[&[&[u8]]] = &[&[
             PROPOSAL,
@@ -129,8 +133,8 @@ impl<'info> InitialiseMilestone<'info> {
             )?;
         }
 
-        self.proposal.milestone_active = true;
         self.proposal.milestone_backers_weighted = 0;
+        self.proposal.milestone_share_sum = 0;
         self.proposal.milestone_reputation_sum = 0;
 
         emit!(MilestoneStarted {
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::seeds::ADMIN_REGISTRY,
+    errors::ProposalError,
+    event::{RoleTransferProposed, RoleTransferred},
+    state::admin_registry::{AdminRegistry, AdminRole},
+};
+
+#[derive(Accounts)]
+pub struct ProposeRoleTransfer<'info> {
+    /// Current holder of the role, or the configurator
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+}
+
+impl<'info> ProposeRoleTransfer<'info> {
+    pub fn handle_propose_role_transfer(&mut self, role: AdminRole, new_holder: Pubkey) -> Result<()> {
+        let signer = self.authority.key();
+        require!(
+            signer == self.admin_registry.holder(role)
+                || signer == self.admin_registry.configurator,
+            ProposalError::NotOwner
+        );
+
+        *self.admin_registry.pending_mut(role) = Some(new_holder);
+
+        emit!(RoleTransferProposed {
+            role,
+            current_holder: self.admin_registry.holder(role),
+            pending_holder: new_holder,
+            proposed_by: signer,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AcceptRoleTransfer<'info> {
+    pub new_holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_REGISTRY],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Account<'info, AdminRegistry>,
+}
+
+impl<'info> AcceptRoleTransfer<'info> {
+    pub fn handle_accept_role_transfer(&mut self, role: AdminRole) -> Result<()> {
+        let new_holder = self.new_holder.key();
+        require!(
+            *self.admin_registry.pending_mut(role) == Some(new_holder),
+            ProposalError::NotOwner
+        );
+
+        let previous_holder = self.admin_registry.holder(role);
+        *self.admin_registry.holder_mut(role) = new_holder;
+        *self.admin_registry.pending_mut(role) = None;
+
+        emit!(RoleTransferred {
+            role,
+            previous_holder,
+            new_holder,
+        });
+
+        Ok(())
+    }
+}
@@ -8,6 +8,14 @@ pub mod ix_set_config;
 pub mod ix_emergency_unlock;
 pub mod ix_reset_pool_launch;
 pub mod ix_reset_airdrop;
+pub mod ix_migrate_proposal;
+pub mod ix_migrate_config;
+pub mod ix_complete_proposal;
+pub mod ix_init_admin_registry;
+pub mod ix_transfer_role;
+pub mod ix_set_paused;
+pub mod ix_config_update;
+pub mod ix_launch_template;
 
 pub use ix_reject_proposal::*;
 pub use ix_snapshot::*;
@@ -18,4 +26,12 @@ pub use ix_end_milestone::*;
 pub use ix_set_config::*;
 pub use ix_emergency_unlock::*;
 pub use ix_reset_pool_launch::*;
-pub use ix_reset_airdrop::*;
\ No newline at end of file
+pub use ix_reset_airdrop::*;
+pub use ix_migrate_proposal::*;
+pub use ix_migrate_config::*;
+pub use ix_complete_proposal::*;
+pub use ix_init_admin_registry::*;
+pub use ix_transfer_role::*;
+pub use ix_set_paused::*;
+pub use ix_config_update::*;
+pub use ix_launch_template::*;
@@ -4,6 +4,10 @@ use crate::{
     errors::ProposalError,
     event::AirdropClaimed,
     state::{backers::Backers, backer_proposal_count::BackerProposalCount, proposal::Proposal, config::Configs},
+    utils::{
+        backer_allocation::{get_allocation_remainder, get_backer_allocation},
+        pause::check_not_paused,
+    },
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -67,12 +71,17 @@ pub struct Airdrop<'info> {
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
     pub config: Account<'info, Configs>,
 }
 
 impl<'info> Airdrop<'info> {
     pub fn handle_airdrop(&mut self) -> Result<()> {
-        require!(self.proposal.is_pool_launched, ProposalError::TargetNotMet);
+        check_not_paused(&self.config, &self.proposal)?;
+        require!(self.proposal.is_launched(), ProposalError::TargetNotMet);
         require!(
             !self.backer_account.initial_airdrop_received,
             ProposalError::AirdropAlreadyRecived
@@ -80,9 +89,13 @@ impl<'info> Airdrop<'info> {
 
         let signer_seeds: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
 
-        let amount = self.config.total_airdrop_amount_per_milestone // TOTAL_AIRDROP_AMOUNT_PER_MILESTONE
-            .checked_div(self.proposal.total_backers)
-            .ok_or(ProposalError::NumericalOverflow)?;
+        let amount = get_backer_allocation(
+            self.proposal.allocation_mode,
+            self.proposal.terms.total_airdrop_amount_per_milestone,
+            self.backer_account.deposit_amount,
+            self.proposal.total_backing,
+            self.proposal.total_backers,
+        )?;
 
         anchor_spl::token::transfer(
             CpiContext::new_with_signer(
@@ -99,6 +112,25 @@ impl<'info> Airdrop<'info> {
 
         self.backer_account.initial_airdrop_received = true;
 
+        // Carry the part of this share that was rounded away; every whole unit it adds up to
+        // is airdrop dust, burned with the next milestone
+        let (remainder, denominator) = get_allocation_remainder(
+            self.proposal.allocation_mode,
+            self.proposal.terms.total_airdrop_amount_per_milestone,
+            self.backer_account.deposit_amount,
+            self.proposal.total_backing,
+            self.proposal.total_backers,
+        )?;
+        let carried = (self.proposal.airdrop_dust_remainder as u128)
+            .checked_add(remainder as u128)
+            .ok_or(ProposalError::NumericalOverflow)?;
+        self.proposal.rounding_dust = self
+            .proposal
+            .rounding_dust
+            .checked_add((carried / denominator as u128) as u64)
+            .ok_or(ProposalError::NumericalOverflow)?;
+        self.proposal.airdrop_dust_remainder = (carried % denominator as u128) as u64;
+
         // Decrement the backer's active proposal count since the pool is launched
         // and the proposal is no longer "active" in the backing phase
         if self.backer_proposal_count.active_count > 0 {
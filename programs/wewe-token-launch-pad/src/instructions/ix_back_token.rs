@@ -7,12 +7,13 @@ use anchor_spl::token::{Mint, TokenAccount};
 use crate::{
     const_pda,
     constant::{
-        seeds::{BACKER, BACKER_PROPOSAL_COUNT, VAULT_AUTHORITY},
+        seeds::{BACKER, BACKER_PROPOSAL_COUNT, PROPOSAL_ESCROW},
         *,
     },
     errors::ProposalError,
     event::ProposalBacked,
-    state::{backers::Backers, backer_proposal_count::BackerProposalCount, proposal::Proposal, config::Configs},
+    state::{backers::Backers, backer_proposal_count::BackerProposalCount, proposal::{Proposal, ProposalStatus}, config::Configs},
+    utils::{escrow::check_escrow_balance, pause::check_not_paused},
 };
 
 #[derive(Accounts)]
@@ -23,13 +24,13 @@ pub struct Contribute<'info> {
     #[account(mut)]
     pub proposal: Account<'info, Proposal>,
 
-    /// CHECK: vault authority
+    /// CHECK: SOL escrow holding this proposal's backing
     #[account(
         mut,
-        seeds = [VAULT_AUTHORITY.as_ref()],
+        seeds = [PROPOSAL_ESCROW, proposal.key().as_ref()],
         bump,
     )]
-    pub vault_authority: SystemAccount<'info>,
+    pub proposal_escrow: SystemAccount<'info>,
 
     #[account(
         mut,
@@ -64,27 +65,36 @@ pub struct Contribute<'info> {
 
     pub system_program: Program<'info, System>,
 
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
     pub config: Account<'info, Configs>,
 }
 
 impl<'info> Contribute<'info> {
-    pub fn handle_deposit_sol(&mut self) -> Result<()> {
+    pub fn handle_deposit_sol(&mut self, amount: u64) -> Result<()> {
+        check_not_paused(&self.config, &self.proposal)?;
+
         let now = Clock::get()?.unix_timestamp;
-        let elapsed = now.saturating_sub(self.proposal.time_started);
 
         require!(
             self.backer.key() != self.proposal.maker,
             ProposalError::CantBackOwnProposal
         );
         
-        require!(elapsed <= SECONDS_TO_DAYS * 3, ProposalError::BackingEnded);
-        require!(!self.proposal.is_rejected, ProposalError::ProposalRejected);
-        require!(
-            !self.proposal.is_pool_launched,
-            ProposalError::PoolAlreadyLaunched
-        );
+        // A launch always comes after the deadline, so the status is checked first
+        match self.proposal.status {
+            ProposalStatus::Backing => {}
+            ProposalStatus::Rejected | ProposalStatus::Cancelled => {
+                return err!(ProposalError::ProposalRejected)
+            }
+            ProposalStatus::Succeeded => return err!(ProposalError::BackingEnded),
+            _ => return err!(ProposalError::PoolAlreadyLaunched),
+        }
+        require!(now <= self.proposal.backing_ends_at, ProposalError::BackingEnded);
         require!(
-            self.proposal.total_backers < MAXIMUM_BACKERS,
+            self.proposal.total_backers < self.proposal.terms.max_backers,
             ProposalError::BackingGoalReached
         );
 
@@ -106,8 +116,26 @@ impl<'info> Contribute<'info> {
             ProposalError::MaxBackedProposalsReached
         );
 
-        let amount = self.config.amount_to_raise_per_user;
-        
+        require!(
+            amount >= self.proposal.terms.min_amount_per_user,
+            ProposalError::BackingAmountTooSmall
+        );
+        require!(
+            amount <= self.proposal.terms.max_amount_per_user,
+            ProposalError::BackingAmountTooBig
+        );
+
+        // In oversubscription mode the hard cap is the target deposits are scaled down to
+        let hard_cap = self.proposal.terms.hard_cap;
+        if hard_cap > 0 && !self.proposal.terms.oversubscription {
+            let total_backing = self
+                .proposal
+                .total_backing
+                .checked_add(amount)
+                .ok_or(ProposalError::NumericalOverflow)?;
+            require!(total_backing <= hard_cap, ProposalError::HardCapExceeded);
+        }
+
         let program_id = self.system_program.to_account_info();
 
         transfer(
@@ -115,7 +143,7 @@ impl<'info> Contribute<'info> {
                 program_id.clone(),
                 Transfer {
                     from: self.backer.to_account_info(),
-                    to: self.vault_authority.to_account_info(),
+                    to: self.proposal_escrow.to_account_info(),
                 },
             ),
             amount,
@@ -123,6 +151,7 @@ impl<'info> Contribute<'info> {
 
         self.backer_account.initial_airdrop_received = false;
         self.backer_account.deposit_amount = amount;
+        self.backer_account.excess_refunded = 0;
         self.proposal.total_backing = self
             .proposal
             .total_backing
@@ -134,6 +163,13 @@ impl<'info> Contribute<'info> {
             .checked_add(1)
             .ok_or(ProposalError::NumericalOverflow)?;
         self.backer_account.settle_cycle = 0;
+        self.backer_account.fee_checkpoint_token = self.proposal.backer_fee_index_token;
+        self.backer_account.fee_checkpoint_wsol = self.proposal.backer_fee_index_wsol;
+
+        check_escrow_balance(
+            &self.proposal_escrow.to_account_info(),
+            self.proposal.total_backing,
+        )?;
 
         // Increment the backer's active proposal count
         
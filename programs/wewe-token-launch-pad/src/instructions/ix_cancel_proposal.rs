@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ProposalError,
+    event::ProposalCancelled,
+    state::proposal::{Proposal, RejectionCause},
+};
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(
+        constraint = maker.key() == proposal.maker @ ProposalError::NotOwner
+    )]
+    pub maker: Signer<'info>,
+
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> CancelProposal<'info> {
+    pub fn handle_cancel_proposal(&mut self, reason: u8) -> Result<()> {
+        // Only Backing and Succeeded can move to Cancelled, i.e. before create_pool
+        self.proposal.reject(RejectionCause::MakerCancelled)?;
+
+        emit!(ProposalCancelled {
+            maker: self.proposal.maker,
+            mint_account: self.proposal.mint_account,
+            proposal_address: self.proposal.key(),
+            reason,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{
+    const_pda::const_authority::VAULT_BUMP,
+    constant::{
+        seeds::{BACKER, BACKER_FEE_VAULT, TOKEN_VAULT, VAULT_AUTHORITY},
+        wsol_pubkey,
+    },
+    errors::ProposalError,
+    event::BackerFeesClaimed,
+    state::{backers::Backers, config::Configs, proposal::Proposal},
+    utils::{backer_fee::pending_backer_fee, pause::check_not_paused},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct ClaimBackerFees<'info> {
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    #[account(mut)]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        mut,
+        seeds = [BACKER, proposal.key().as_ref(), backer.key().as_ref()],
+        bump,
+    )]
+    pub backer_account: Box<Account<'info, Backers>>,
+
+    /// CHECK: vault authority
+    #[account(
+        mut,
+        seeds = [
+            VAULT_AUTHORITY.as_ref(),
+        ],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    #[account(address = proposal.mint_account @ ProposalError::IncorrectAccount)]
+    pub mint_account: Box<Account<'info, Mint>>,
+
+    #[account(address = wsol_pubkey::ID @ ProposalError::IncorrectAccount)]
+    pub quote_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT, vault_authority.key().as_ref(), mint_account.key().as_ref()],
+        token::mint = mint_account,
+        token::authority = vault_authority,
+        bump,
+    )]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Per-proposal WSOL account holding the backer share of pool fees
+    #[account(
+        init_if_needed,
+        payer = backer,
+        seeds = [BACKER_FEE_VAULT, proposal.key().as_ref()],
+        token::mint = quote_mint,
+        token::authority = vault_authority,
+        bump,
+    )]
+    pub backer_fee_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = backer,
+        associated_token::mint = mint_account,
+        associated_token::authority = backer,
+    )]
+    pub backer_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = backer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = backer,
+    )]
+    pub backer_wsol_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Box<Account<'info, Configs>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimBackerFees<'info> {
+    pub fn handle_claim_backer_fees(&mut self) -> Result<()> {
+        check_not_paused(&self.config, &self.proposal)?;
+        require!(self.proposal.is_launched(), ProposalError::TargetNotMet);
+
+        let token_amount = pending_backer_fee(
+            self.backer_account.deposit_amount,
+            self.proposal.backer_fee_index_token,
+            self.backer_account.fee_checkpoint_token,
+        )?;
+        let wsol_amount = pending_backer_fee(
+            self.backer_account.deposit_amount,
+            self.proposal.backer_fee_index_wsol,
+            self.backer_account.fee_checkpoint_wsol,
+        )?;
+        require!(
+            token_amount > 0 || wsol_amount > 0,
+            ProposalError::NoBackerFeesToClaim
+        );
+
+        self.backer_account.fee_checkpoint_token = self.proposal.backer_fee_index_token;
+        self.backer_account.fee_checkpoint_wsol = self.proposal.backer_fee_index_wsol;
+        self.proposal.backer_fee_pool_token = self
+            .proposal
+            .backer_fee_pool_token
+            .checked_sub(token_amount)
+            .ok_or(ProposalError::NumericalOverflow)?;
+        self.proposal.backer_fee_pool_wsol = self
+            .proposal
+            .backer_fee_pool_wsol
+            .checked_sub(wsol_amount)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
+
+        if token_amount > 0 {
+            anchor_spl::token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.token_vault.to_account_info(),
+                        to: self.backer_token_account.to_account_info(),
+                        authority: self.vault_authority.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                token_amount,
+            )?;
+        }
+
+        if wsol_amount > 0 {
+            anchor_spl::token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.backer_fee_vault.to_account_info(),
+                        to: self.backer_wsol_account.to_account_info(),
+                        authority: self.vault_authority.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                wsol_amount,
+            )?;
+        }
+
+        emit!(BackerFeesClaimed {
+            backer: self.backer.key(),
+            proposal: self.proposal.key(),
+            token_amount,
+            wsol_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    constant::seeds::{BACKER, PROPOSAL_ESCROW},
+    errors::ProposalError,
+    event::ExcessClaimed,
+    state::{
+        backers::Backers,
+        config::Configs,
+        proposal::{Proposal, ProposalStatus},
+    },
+    utils::pause::check_not_paused,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClaimExcess<'info> {
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [BACKER, proposal.key().as_ref(), backer.key().as_ref()],
+        bump,
+    )]
+    pub backer_account: Account<'info, Backers>,
+
+    /// CHECK: SOL escrow holding this proposal's backing
+    #[account(
+        mut,
+        seeds = [PROPOSAL_ESCROW, proposal.key().as_ref()],
+        bump,
+    )]
+    pub proposal_escrow: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Configs>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimExcess<'info> {
+    pub fn handle_claim_excess(&mut self, bumps: &ClaimExcessBumps) -> Result<()> {
+        check_not_paused(&self.config, &self.proposal)?;
+
+        // The pro-rata share is only fixed once backing has closed above the target
+        require!(
+            self.proposal.status == ProposalStatus::Succeeded || self.proposal.is_launched(),
+            ProposalError::BackingNotEnded
+        );
+
+        let amount = self
+            .proposal
+            .excess_of(self.backer_account.deposit_amount)?
+            .checked_sub(self.backer_account.excess_refunded)
+            .ok_or(ProposalError::NumericalOverflow)?;
+        require!(amount > 0, ProposalError::NoExcessToClaim);
+
+        let proposal_key = self.proposal.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            PROPOSAL_ESCROW,
+            proposal_key.as_ref(),
+            &[bumps.proposal_escrow],
+        ]];
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: self.proposal_escrow.to_account_info(),
+                    to: self.backer.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        self.backer_account.excess_refunded = self
+            .backer_account
+            .excess_refunded
+            .checked_add(amount)
+            .ok_or(ProposalError::NumericalOverflow)?;
+        self.proposal.excess_refunded = self
+            .proposal
+            .excess_refunded
+            .checked_add(amount)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
+        emit!(ExcessClaimed {
+            backer: self.backer.key(),
+            proposal_address: self.proposal.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -6,7 +6,8 @@ use crate::{
     },
     errors::ProposalError,
     event::AirdropClaimed,
-    state::{backers::Backers, proposal::Proposal},
+    state::{backers::Backers, config::Configs, proposal::Proposal},
+    utils::pause::check_not_paused,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -60,11 +61,18 @@ pub struct Claim<'info> {
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Configs>,
 }
 
 impl<'info> Claim<'info> {
     pub fn handler_claim_milestone_reward(&mut self) -> Result<()> {
-        require!(self.proposal.is_pool_launched, ProposalError::TargetNotMet);
+        check_not_paused(&self.config, &self.proposal)?;
+        require!(self.proposal.is_launched(), ProposalError::TargetNotMet);
 
         let signer_seeds: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
 
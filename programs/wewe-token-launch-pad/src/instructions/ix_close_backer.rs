@@ -0,0 +1,80 @@
+use crate::{
+    constant::seeds::BACKER,
+    errors::ProposalError,
+    state::{
+        backers::Backers,
+        proposal::{Proposal, ProposalStatus},
+    },
+    utils::backer_fee::pending_backer_fee,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseBacker<'info> {
+    /// Backer that paid the rent; receives it back
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [BACKER, proposal.key().as_ref(), backer.key().as_ref()],
+        bump,
+        close = backer,
+    )]
+    pub backer_account: Account<'info, Backers>,
+}
+
+impl<'info> CloseBacker<'info> {
+    pub fn handle_close_backer(&mut self) -> Result<()> {
+        require!(
+            self.proposal.status == ProposalStatus::Completed,
+            ProposalError::ProposalNotCompleted
+        );
+
+        // Nothing the backer is still owed may be closed away
+        require!(
+            self.backer_account.initial_airdrop_received
+                && self.backer_account.claim_amount == 0
+                && self.backer_account.claimed_upto == self.backer_account.settle_cycle,
+            ProposalError::ClaimableAmountRemaining
+        );
+        let pending_token = pending_backer_fee(
+            self.backer_account.deposit_amount,
+            self.proposal.backer_fee_index_token,
+            self.backer_account.fee_checkpoint_token,
+        )?;
+        let pending_wsol = pending_backer_fee(
+            self.backer_account.deposit_amount,
+            self.proposal.backer_fee_index_wsol,
+            self.backer_account.fee_checkpoint_wsol,
+        )?;
+        require!(
+            pending_token == 0 && pending_wsol == 0,
+            ProposalError::ClaimableAmountRemaining
+        );
+        require!(
+            self.proposal.excess_of(self.backer_account.deposit_amount)?
+                == self.backer_account.excess_refunded,
+            ProposalError::ClaimableAmountRemaining
+        );
+
+        self.proposal.closed_backers = self
+            .proposal
+            .closed_backers
+            .checked_add(1)
+            .ok_or(ProposalError::NumericalOverflow)?;
+        self.proposal.closed_backing = self
+            .proposal
+            .closed_backing
+            .checked_add(self.backer_account.deposit_amount)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
+        // The airdrop already released this proposal's slot in BackerProposalCount;
+        // the counter stays open since refund, withdraw_backing and airdrop require it
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    const_pda::const_authority::VAULT_BUMP,
+    constant::seeds::{BACKER_FEE_VAULT, VAULT_AUTHORITY},
+    errors::ProposalError,
+    event::BackerFeeVaultClosed,
+    state::{
+        config::Configs,
+        proposal::{Proposal, ProposalStatus},
+    },
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{close_account, CloseAccount, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CloseBackerFeeVault<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    /// CHECK: vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [BACKER_FEE_VAULT, proposal.key().as_ref()],
+        token::authority = vault_authority,
+        bump,
+    )]
+    pub backer_fee_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: treasury configured by the treasury manager
+    #[account(mut, address = config.treasury @ ProposalError::IncorrectAccount)]
+    pub wewe_treasury: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Box<Account<'info, Configs>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseBackerFeeVault<'info> {
+    pub fn handle_close_backer_fee_vault(&mut self) -> Result<()> {
+        // Only once no backer account is left to claim from it
+        require!(
+            self.proposal.status == ProposalStatus::Completed
+                && self.proposal.closed_backers == self.proposal.total_backers,
+            ProposalError::ProposalNotClosable
+        );
+
+        // Closing a WSOL account unwraps it: the rounding dust leaves with the rent
+        let amount = self.backer_fee_vault.to_account_info().lamports();
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.backer_fee_vault.to_account_info(),
+                destination: self.wewe_treasury.to_account_info(),
+                authority: self.vault_authority.to_account_info(),
+            },
+            &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]],
+        ))?;
+        self.proposal.backer_fee_pool_wsol = 0;
+
+        emit!(BackerFeeVaultClosed {
+            proposal_address: self.proposal.key(),
+            treasury: self.wewe_treasury.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    constant::seeds::PROPOSAL_ESCROW,
+    errors::ProposalError,
+    event::ProposalClosed,
+    state::proposal::{Proposal, ProposalStatus},
+};
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// CHECK: SOL escrow holding this proposal's backing
+    #[account(
+        mut,
+        seeds = [PROPOSAL_ESCROW, proposal.key().as_ref()],
+        bump,
+    )]
+    pub proposal_escrow: SystemAccount<'info>,
+
+    /// CHECK: account that paid the proposal rent at creation
+    #[account(
+        mut,
+        address = proposal.rent_payer @ ProposalError::IncorrectAccount,
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseProposal<'info> {
+    pub fn handle_close_proposal(&mut self, bumps: &CloseProposalBumps) -> Result<()> {
+        // A launched proposal stays open: its locked pool position keeps earning fees and
+        // claim_pool_fee needs the proposal to split them
+        require!(
+            !self.proposal.is_launched(),
+            ProposalError::PoolPositionOpen
+        );
+        let closable = match self.proposal.status {
+            ProposalStatus::Rejected | ProposalStatus::Cancelled => {
+                self.proposal.total_backing == 0
+            }
+            _ => false,
+        };
+        require!(closable, ProposalError::ProposalNotClosable);
+
+        // Return the escrow's rent-exempt reserve along with the proposal rent
+        let escrow_lamports = self.proposal_escrow.lamports();
+        if escrow_lamports > 0 {
+            let proposal_key = self.proposal.key();
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.proposal_escrow.to_account_info(),
+                        to: self.rent_payer.to_account_info(),
+                    },
+                    &[&[
+                        PROPOSAL_ESCROW,
+                        proposal_key.as_ref(),
+                        &[bumps.proposal_escrow],
+                    ]],
+                ),
+                escrow_lamports,
+            )?;
+        }
+
+        emit!(ProposalClosed {
+            proposal_address: self.proposal.key(),
+            maker: self.proposal.maker,
+            rent_payer: self.rent_payer.key(),
+        });
+
+        Ok(())
+    }
+}
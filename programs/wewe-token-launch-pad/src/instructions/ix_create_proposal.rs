@@ -3,14 +3,22 @@ use std::ops::Sub;
 use crate::{
     const_pda,
     constant::{
-        seeds::{MAKER, PROPOSAL, TOKEN_VAULT, VAULT_AUTHORITY},
+        seeds::{LAUNCH_TEMPLATE, MAKER, PROPOSAL, PROPOSAL_ESCROW, TOKEN_VAULT, VAULT_AUTHORITY},
         ANCHOR_DISCRIMINATOR, MINT_DECIMALS,
     },
     errors::ProposalError,
     event::ProposalCreated,
-    state::{maker::MakerAccount, proposal::Proposal,config::Configs},
+    state::{
+        maker::MakerAccount,
+        proposal::{Proposal, ProposalStatus},
+        config::{Configs, PoolFeeSplit},
+        launch_template::LaunchTemplate,
+    },
+};
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
 };
-use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
 #[derive(Accounts)]
@@ -39,6 +47,14 @@ pub struct CreateProposal<'info> {
     )]
     pub proposal: Account<'info, Proposal>,
 
+    /// CHECK: SOL escrow holding this proposal's backing
+    #[account(
+        mut,
+        seeds = [PROPOSAL_ESCROW, proposal.key().as_ref()],
+        bump,
+    )]
+    pub proposal_escrow: SystemAccount<'info>,
+
     /// CHECK: vault authority
     #[account(
         mut,
@@ -82,7 +98,18 @@ pub struct CreateProposal<'info> {
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
     pub config: Account<'info, Configs>,
+    /// Launch economics to use instead of the global config
+    #[account(
+        seeds = [LAUNCH_TEMPLATE, &launch_template.template_id.to_le_bytes()],
+        bump = launch_template.bump,
+        constraint = !launch_template.retired @ ProposalError::LaunchTemplateRetired,
+    )]
+    pub launch_template: Option<Account<'info, LaunchTemplate>>,
 }
 
 impl<'info> CreateProposal<'info> {
@@ -91,30 +118,109 @@ impl<'info> CreateProposal<'info> {
         token_name: String,
         token_symbol: String,
         token_uri: String,
+        backing_duration: i64,
+        pool_fee_split: Option<PoolFeeSplit>,
         bumps: &CreateProposalBumps,
     ) -> Result<()> {
         require!(token_name.len() <= 32, ProposalError::LenthTooLong);
         require!(token_symbol.len() <= 10, ProposalError::LenthTooLong);
         require!(token_uri.len() <= 200, ProposalError::LenthTooLong);
+        require!(
+            backing_duration >= self.config.min_backing_duration
+                && backing_duration <= self.config.max_backing_duration,
+            ProposalError::InvalidBackingDuration
+        );
+
+        // The backers' terms come from the chosen template, or the global config without one
+        let (launch_template, terms, allocation_mode, template_split) = match &self.launch_template {
+            Some(template) => (
+                Some(template.template_id),
+                template.terms,
+                template.allocation_mode,
+                template.pool_fee_split,
+            ),
+            None => (
+                None,
+                self.config.proposal_terms(),
+                self.config.allocation_mode,
+                None,
+            ),
+        };
+
+        // The maker's override is measured against the split it replaces
+        if let Some(split) = pool_fee_split {
+            require!(
+                split.is_valid_override_of(&template_split.unwrap_or(self.config.pool_fee_split)),
+                ProposalError::InvalidFeeSplit
+            );
+        }
+        let pool_fee_split = pool_fee_split.or(template_split);
+
+        // Fund the escrow with its rent-exempt minimum so refunds can drain it to exactly zero backing
+        let escrow_rent = Rent::get()?
+            .minimum_balance(0)
+            .saturating_sub(self.proposal_escrow.lamports());
+        if escrow_rent > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.payer.to_account_info(),
+                        to: self.proposal_escrow.to_account_info(),
+                    },
+                ),
+                escrow_rent,
+            )?;
+        }
 
         let now = Clock::get()?.unix_timestamp;
+        let backing_ends_at = now
+            .checked_add(backing_duration)
+            .ok_or(ProposalError::NumericalOverflow)?;
         self.proposal.set_inner(Proposal {
             maker: self.maker.key(),
             mint_account: self.mint_account.key(),
             total_backing: 0,
             time_started: now,
+            backing_ends_at,
             bump: bumps.proposal,
-            is_rejected: false,
+            status: ProposalStatus::Backing,
+            rejection_cause: None,
             proposal_id: self.maker_account.proposal_count,
-            is_pool_launched: false,
             total_backers: 0,
             current_airdrop_cycle: 1,
-            milestone_active: false,
             milestone_units_assigned: 0,
             milestone_backers_weighted: 0,
             milestone_reputation_sum: 0,
+            allocation_mode,
+            milestone_share_sum: 0,
+            rounding_dust: 0,
+            airdrop_dust_remainder: 0,
             launch_timestamp: None,
             emergency_unlocked: false,
+            rent_payer: self.payer.key(),
+            closed_backers: 0,
+            closed_backing: 0,
+            frozen: false,
+            pool_fee_split,
+            backer_fee_pool_token: 0,
+            backer_fee_pool_wsol: 0,
+            backer_fee_index_token: 0,
+            backer_fee_index_wsol: 0,
+            pool_address: None,
+            pool_position: None,
+            pool_fee_schedule: None,
+            pool_activation_point: None,
+            pool_sqrt_min_price: 0,
+            pool_sqrt_max_price: 0,
+            residual_base: 0,
+            residual_quote: 0,
+            residual_policy: None,
+            residual_quote_policy: None,
+            terms,
+            launch_terms: self.config.pool_launch_terms(),
+            launch_template,
+            excess_refunded: 0,
             token_name: token_name.clone(),
             token_symbol: token_symbol.clone(),
             token_uri: token_uri.clone(),
@@ -129,6 +235,7 @@ impl<'info> CreateProposal<'info> {
             proposal_address: self.proposal.key(),
             proposal_index: self.maker_account.proposal_count.sub(1),
             start_time: now,
+            backing_ends_at,
             token_name,
             token_symbol,
             token_uri,
@@ -137,6 +244,7 @@ impl<'info> CreateProposal<'info> {
             metadata_account: anchor_lang::solana_program::pubkey::Pubkey::default(), // Will be created later
             maker_account: self.maker_account.key(),
             proposal_bump: bumps.proposal,
+            launch_template,
         });
 
         Ok(())
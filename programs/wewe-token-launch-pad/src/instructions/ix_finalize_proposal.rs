@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ProposalError,
+    event::ProposalFinalized,
+    state::proposal::{Proposal, ProposalStatus, RejectionCause},
+};
+
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    /// Anyone can crank finalization once the backing window has closed
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> FinalizeProposal<'info> {
+    pub fn handle_finalize_proposal(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            now > self.proposal.backing_ends_at,
+            ProposalError::BackingNotEnded
+        );
+        require!(
+            self.proposal.status == ProposalStatus::Backing,
+            ProposalError::ProposalAlreadyFinalized
+        );
+
+        // A raise that missed min_backers or the soft cap becomes refundable, otherwise it is ready to launch
+        let succeeded = self.proposal.total_backers >= self.proposal.terms.min_backers
+            && self.proposal.total_backing >= self.proposal.terms.soft_cap;
+        if succeeded {
+            self.proposal.transition(ProposalStatus::Succeeded)?;
+        } else {
+            self.proposal.reject(RejectionCause::FailedRaise)?;
+        }
+
+        emit!(ProposalFinalized {
+            proposal_address: self.proposal.key(),
+            maker: self.proposal.maker,
+            mint_account: self.proposal.mint_account,
+            succeeded,
+            total_backers: self.proposal.total_backers,
+            total_backing: self.proposal.total_backing,
+            effective_backing: self.proposal.effective_backing(),
+            finalized_by: self.payer.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -2,22 +2,33 @@ use anchor_lang::system_program::{transfer, Transfer};
 use anchor_spl::{
     associated_token::AssociatedToken,
     token,
-    token::{mint_to, Mint, MintTo, Transfer as TokenTransfer},
+    token::{
+        burn, close_account, mint_to, Burn, CloseAccount, Mint, MintTo,
+        Transfer as TokenTransfer,
+    },
     token_interface::{TokenAccount, TokenInterface},
 };
-use damm_v2_cpi::{params::fee_parameters::{BaseFeeParameters, PoolFeeParameters}};
 use damm_v2_cpi::constants::{MIN_SQRT_PRICE, MAX_SQRT_PRICE};
 use std::u64;
 
 use crate::{
     const_pda::{self, const_authority::VAULT_BUMP},
     constant::{
-        seeds::{PROPOSAL, TOKEN_VAULT, VAULT_AUTHORITY},
+        seeds::{PROPOSAL, PROPOSAL_ESCROW, TEMP_WSOL, TOKEN_VAULT, VAULT_AUTHORITY},
         *,
     },
-    event::{CoinLaunched, ProposalRejected},
-    state::{proposal::Proposal,config::Configs},
-    utils::pool_liqudity::get_liquidity_delta,
+    event::CoinLaunched,
+    state::{
+        proposal::{Proposal, ProposalStatus},
+        config::{ActivationType, Configs, ResidualPolicy, ResidualQuotePolicy},
+    },
+    utils::{
+        escrow::check_escrow_balance, pause::check_not_paused, pool_fee::pool_fee_parameters,
+        pool_liqudity::{
+            get_liquidity_delta, get_sqrt_price_bound, get_sqrt_price_from_amounts,
+            is_sqrt_price_within_tolerance,
+        },
+    },
     *,
 };
 
@@ -34,6 +45,13 @@ pub struct DammV2<'info> {
         bump,
     )]
     pub vault_authority: SystemAccount<'info>,
+    /// CHECK: SOL escrow holding this proposal's backing
+    #[account(
+        mut,
+        seeds = [PROPOSAL_ESCROW, proposal.key().as_ref()],
+        bump,
+    )]
+    pub proposal_escrow: SystemAccount<'info>,
     #[account(
         mut,
         seeds = [TOKEN_VAULT, vault_authority.key().as_ref(), base_mint.key().as_ref()],
@@ -133,36 +151,61 @@ pub struct DammV2<'info> {
     /// System program.
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
     pub config: Account<'info, Configs>,
+    /// Temporary WSOL account used to unwrap the residual quote (closed in this instruction)
+    #[account(
+        init,
+        payer = payer,
+        seeds = [TEMP_WSOL, proposal.key().as_ref()],
+        bump,
+        token::mint = quote_mint,
+        token::authority = vault_authority,
+        token::token_program = token_quote_program,
+    )]
+    pub temp_wsol: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: treasury wallet, receives the residual under the `Treasury` policies
+    #[account(mut, address = config.treasury @ ProposalError::IncorrectAccount)]
+    pub wewe_treasury: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = base_mint,
+        associated_token::authority = wewe_treasury,
+        associated_token::token_program = token_base_program,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: incinerator, receives residual lamports under `ResidualQuotePolicy::Burn`
+    #[account(mut, address = incinerator::ID @ ProposalError::IncorrectAccount)]
+    pub incinerator: UncheckedAccount<'info>,
 }
 
 impl<'info> DammV2<'info> {
-    pub fn handle_create_pool(&mut self, sqrt_price: u128) -> Result<()> {
-        // Chain service pubkey must be the signer (payer) and pool_creator_authority
+    pub fn handle_create_pool(
+        &mut self,
+        sqrt_price: u128,
+        activation_delay: Option<u64>,
+        bumps: &DammV2Bumps,
+    ) -> Result<()> {
+        // Chain service pubkey must be the signer (payer) and pool_creator_authority.
+        // It stays a constant because the DAMM config pins pool_creator_authority to it.
         require!(
             self.payer.key() == chain_service_pubkey::ID,
             ProposalError::NotOwner
         );
+        check_not_paused(&self.config, &self.proposal)?;
 
-        let now = Clock::get()?.unix_timestamp;
-        let elapsed = now.saturating_sub(self.proposal.time_started);
-        if elapsed >= SECONDS_TO_DAYS * 3 && self.proposal.total_backers < self.config.min_backers // MINIMUM_BACKERS 
-        {
-            self.proposal.is_rejected = true;
-            emit!(ProposalRejected {
-                maker: self.proposal.maker,
-                proposal_address: self.proposal.key(),
-                mint_account: self.proposal.mint_account.key(),
-            });
-        }
-        require!(!self.proposal.is_rejected, ProposalError::ProposalRejected);
         require!(
-            !self.proposal.is_pool_launched,
+            !self.proposal.is_launched(),
             ProposalError::PoolAlreadyLaunched
         );
+        // finalize_proposal checks min_backers and the soft cap once the backing window is over
         require!(
-            self.proposal.total_backers >= self.config.min_backers, // MINIMUM_BACKERS,
-            ProposalError::TargetNotMet
+            self.proposal.status == ProposalStatus::Succeeded,
+            ProposalError::ProposalNotSucceeded
         );
 
         // Mint tokens to token vault at the start
@@ -178,7 +221,7 @@ impl<'info> DammV2<'info> {
         let pow = 10u64
             .checked_pow(mint_data.decimals as u32)
             .ok_or(ProposalError::NumericalOverflow)?;
-        let amount = self.config.total_mint
+        let amount = self.proposal.terms.total_mint
             .checked_mul(pow)
             .ok_or(ProposalError::NumericalOverflow)?;
         
@@ -201,15 +244,22 @@ impl<'info> DammV2<'info> {
         let signer_seeds: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
         
 
+        check_escrow_balance(
+            &self.proposal_escrow.to_account_info(),
+            self.proposal.escrowed_backing(),
+        )?;
+
         fund_creator_authority(FundCreatorAuthorityAccounts {
             proposal: &self.proposal,
+            proposal_escrow: &self.proposal_escrow,
+            proposal_escrow_bump: bumps.proposal_escrow,
             wsol_vault: &self.wsol_vault,
             system_program: &self.system_program,
             creator_authority: &self.vault_authority,
             maker_token_account: &self.maker_token_account,
             token_program_a: &self.token_base_program,
             token_vault: &self.token_vault,
-            maker_amount: self.config.maker_token_amount,
+            maker_amount: self.proposal.terms.maker_token_amount,
         })?;
 
         // Reload vaults after funding to get updated balances
@@ -225,14 +275,38 @@ impl<'info> DammV2<'info> {
 
         // Calculate token amounts (matching SDK flow: tokenAAmount and tokenBAmount)
         // tokenAAmount = total_pool_tokens * 10^decimals (base token amount)
-        let base_amount: u64 = self.config.total_pool_tokens * 10u64.pow(MINT_DECIMALS as u32);
-        // tokenBAmount = total_backing in lamports (quote token amount, WSOL)
-        let quote_amount: u64 = self.proposal.total_backing;
+        let base_amount: u64 = self.proposal.terms.total_pool_tokens * 10u64.pow(MINT_DECIMALS as u32);
+        // tokenBAmount = effective backing in lamports (quote token amount, WSOL);
+        // oversubscribed deposits above the target stay in the escrow for claim_excess
+        let quote_amount: u64 = self.proposal.effective_backing();
 
-        // Use MIN_SQRT_PRICE and MAX_SQRT_PRICE constants (matching SDK: MIN_SQRT_PRICE, MAX_SQRT_PRICE)
-        // The config values may be 0 or uninitialized, but we need the actual constants
-        let sqrt_min_price = MIN_SQRT_PRICE;
-        let sqrt_max_price = MAX_SQRT_PRICE;
+        // Launch at the price the raise implies; the operator's value may stray from it by at
+        // most sqrt_price_tolerance_bps
+        let expected_sqrt_price = get_sqrt_price_from_amounts(base_amount, quote_amount)?;
+        require!(
+            is_sqrt_price_within_tolerance(
+                sqrt_price,
+                expected_sqrt_price,
+                self.proposal.launch_terms.sqrt_price_tolerance_bps,
+            )?,
+            ProposalError::SqrtPriceOutOfTolerance
+        );
+        require!(
+            sqrt_price > MIN_SQRT_PRICE && sqrt_price < MAX_SQRT_PRICE,
+            ProposalError::SqrtPriceOutOfTolerance
+        );
+
+        // Concentrate the position around the initial price when a range is configured;
+        // an unbounded side falls back to DAMM's MIN_SQRT_PRICE / MAX_SQRT_PRICE
+        let price_range = self.proposal.launch_terms.pool_price_range;
+        let sqrt_min_price = match price_range.min_price_bps {
+            0 => MIN_SQRT_PRICE,
+            bps => get_sqrt_price_bound(sqrt_price, bps, MIN_SQRT_PRICE, MAX_SQRT_PRICE)?,
+        };
+        let sqrt_max_price = match price_range.max_price_bps {
+            0 => MAX_SQRT_PRICE,
+            bps => get_sqrt_price_bound(sqrt_price, bps, MIN_SQRT_PRICE, MAX_SQRT_PRICE)?,
+        };
 
         // Calculate liquidity delta (matching SDK: cpAmm.getLiquidityDelta)
         // This calculates the minimum liquidity from both token amounts to ensure balanced pool
@@ -258,11 +332,37 @@ impl<'info> DammV2<'info> {
         // - tokenBAmount -> quote_amount
         // - initSqrtPrice -> sqrt_price (calculated from price in test)
         // - liquidityDelta -> liquidity (calculated above)
-        // - sqrtMinPrice -> MIN_SQRT_PRICE
-        // - sqrtMaxPrice -> MAX_SQRT_PRICE
-        // - poolFees -> configured fee parameters
-        // - activationType -> 1 (Timestamp)
-        // - collectFeeMode -> 1 (BothToken)
+        // - sqrtMinPrice -> sqrt_min_price (configured range)
+        // - sqrtMaxPrice -> sqrt_max_price (configured range)
+        // - poolFees -> PoolLaunchTerms::pool_fee_schedule
+        // - activationType / activationPoint -> PoolLaunchTerms::pool_activation plus the delay
+        // - collectFeeMode -> PoolLaunchTerms::pool_fee_schedule
+        let pool_fee_schedule = self.proposal.launch_terms.pool_fee_schedule;
+        let pool_activation = self.proposal.launch_terms.pool_activation;
+
+        // A delayed activation lets the airdrop land before trading opens
+        let delay = activation_delay.unwrap_or(pool_activation.default_delay);
+        require!(
+            delay <= pool_activation.max_delay,
+            ProposalError::InvalidActivationDelay
+        );
+        let activation_point = if delay == 0 {
+            None
+        } else {
+            let clock = Clock::get()?;
+            let current_point = match pool_activation.activation_type {
+                ActivationType::Slot => clock.slot,
+                ActivationType::Timestamp => clock
+                    .unix_timestamp
+                    .try_into()
+                    .map_err(|_| ProposalError::TypeCastFailed)?,
+            };
+            Some(
+                current_point
+                    .checked_add(delay)
+                    .ok_or(ProposalError::NumericalOverflow)?,
+            )
+        };
         damm_v2_cpi::cpi::initialize_pool_with_dynamic_config(
             CpiContext::new_with_signer(
                 self.amm_program.to_account_info(),
@@ -292,32 +392,15 @@ impl<'info> DammV2<'info> {
                 signer_seeds,
             ),
             damm_v2_cpi::InitializeCustomizablePoolParameters {
-                pool_fees: PoolFeeParameters {
-                    base_fee: BaseFeeParameters {
-                        cliff_fee_numerator: 990_000_000, // 50% fee (denominator)
-                        base_fee_mode: damm_v2_cpi::state::fee::BaseFeeMode::FeeSchedulerLinear as u8,
-                        first_factor: 100,
-                        second_factor: 1u64.to_le_bytes(),
-                        third_factor: 9_700_000, // Ending Fee = Cliff Fee Numerator − (Number Of Periods × Reduction Factor)
-                        /*
-                            firstFactor: number // numberOfPeriod
-                            secondFactor: BN // periodFrequency
-                            thirdFactor: BN // reductionFactor
-                            baseFeeMode: BaseFeeMode // 0 or 1
-                         */
-
-                    },
-                    dynamic_fee: None,
-                    ..Default::default()
-                },
-                sqrt_min_price: MIN_SQRT_PRICE,
-                sqrt_max_price: MAX_SQRT_PRICE,
+                pool_fees: pool_fee_parameters(&pool_fee_schedule),
+                sqrt_min_price,
+                sqrt_max_price,
                 has_alpha_vault: false, // Matching SDK: hasAlphaVault: false
                 liquidity, // liquidityDelta from SDK
                 sqrt_price, // initSqrtPrice from SDK
-                activation_type: 1, // Matching SDK: ActivationType.Timestamp
-                collect_fee_mode: 1, 
-                activation_point: None, // Matching SDK: activationPoint: null
+                activation_type: pool_activation.activation_type.to_damm(),
+                collect_fee_mode: pool_fee_schedule.collect_fee_mode,
+                activation_point,
             },
         )?;
 
@@ -363,16 +446,110 @@ impl<'info> DammV2<'info> {
             quote_amount,
         )?;
 
+        // Whatever the liquidity delta did not consume is still in the vaults; the WSOL
+        // vault is shared, so the residual is measured against this launch's deposit only
+        let base_deposited = token_vault_before
+            .checked_sub(self.token_vault.amount)
+            .ok_or(ProposalError::NumericalOverflow)?;
+        let quote_deposited = wsol_vault_before
+            .checked_sub(self.wsol_vault.amount)
+            .ok_or(ProposalError::NumericalOverflow)?;
+        let residual_base = base_amount.saturating_sub(base_deposited);
+        let residual_quote = quote_amount.saturating_sub(quote_deposited);
+        let residual_policy = self.proposal.launch_terms.residual_policy;
+        let residual_quote_policy = self.proposal.launch_terms.residual_quote_policy;
+
+        if residual_base > 0 {
+            match residual_policy {
+                ResidualPolicy::Burn => burn(
+                    CpiContext::new_with_signer(
+                        self.token_base_program.to_account_info(),
+                        Burn {
+                            mint: self.base_mint.to_account_info(),
+                            from: self.token_vault.to_account_info(),
+                            authority: self.vault_authority.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    residual_base,
+                )?,
+                ResidualPolicy::Treasury => anchor_spl::token::transfer(
+                    CpiContext::new_with_signer(
+                        self.token_base_program.to_account_info(),
+                        TokenTransfer {
+                            from: self.token_vault.to_account_info(),
+                            to: self.treasury_token_account.to_account_info(),
+                            authority: self.vault_authority.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    residual_base,
+                )?,
+            }
+        }
+
+        // Unwrap the residual WSOL: move it into the temporary account, close that into
+        // the payer, then forward exactly the residual lamports
+        if residual_quote > 0 {
+            anchor_spl::token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_quote_program.to_account_info(),
+                    TokenTransfer {
+                        from: self.wsol_vault.to_account_info(),
+                        to: self.temp_wsol.to_account_info(),
+                        authority: self.vault_authority.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                residual_quote,
+            )?;
+        }
+        close_account(CpiContext::new_with_signer(
+            self.token_quote_program.to_account_info(),
+            CloseAccount {
+                account: self.temp_wsol.to_account_info(),
+                destination: self.payer.to_account_info(),
+                authority: self.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+        if residual_quote > 0 {
+            let destination = match residual_quote_policy {
+                ResidualQuotePolicy::Burn => self.incinerator.to_account_info(),
+                ResidualQuotePolicy::Treasury => self.wewe_treasury.to_account_info(),
+            };
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.payer.to_account_info(),
+                        to: destination,
+                    },
+                ),
+                residual_quote,
+            )?;
+        }
+
         // Only set flag AFTER all validations pass
         let now = Clock::get()?.unix_timestamp;
-        self.proposal.is_pool_launched = true;
+        self.proposal.transition(ProposalStatus::Launched)?;
         self.proposal.launch_timestamp = Some(now);
+        self.proposal.pool_address = Some(self.pool.key());
+        self.proposal.pool_position = Some(self.position.key());
+        self.proposal.pool_fee_schedule = Some(pool_fee_schedule);
+        self.proposal.pool_activation_point = activation_point;
+        self.proposal.pool_sqrt_min_price = sqrt_min_price;
+        self.proposal.pool_sqrt_max_price = sqrt_max_price;
+        self.proposal.residual_base = residual_base;
+        self.proposal.residual_quote = residual_quote;
+        self.proposal.residual_policy = Some(residual_policy);
+        self.proposal.residual_quote_policy = Some(residual_quote_policy);
 
         emit!(CoinLaunched {
             proposal_address: self.proposal.key(),
             mint_account: self.base_mint.key(),
             quote_mint: self.quote_mint.key(),
-            total_sol_raised: self.proposal.total_backing,
+            total_sol_raised: quote_amount,
             pool_address: self.pool.key(),
             token_vault: self.token_vault.key(),
             wsol_vault: self.wsol_vault.key(),
@@ -382,6 +559,17 @@ impl<'info> DammV2<'info> {
             position_nft_account: self.position_nft_account.key(),
             sqrt_price,
             liquidity,
+            pool_fee_schedule,
+            activation_type: pool_activation.activation_type,
+            activation_point,
+            sqrt_min_price,
+            sqrt_max_price,
+            base_deposited,
+            quote_deposited,
+            residual_base,
+            residual_quote,
+            residual_policy,
+            residual_quote_policy,
         });
 
         Ok(())
@@ -390,6 +578,8 @@ impl<'info> DammV2<'info> {
 
 pub struct FundCreatorAuthorityAccounts<'b, 'info> {
     pub proposal: &'b Account<'info, Proposal>,
+    pub proposal_escrow: &'b SystemAccount<'info>,
+    pub proposal_escrow_bump: u8,
     pub token_program_a: &'b Interface<'info, TokenInterface>,
     pub token_vault: &'b Box<InterfaceAccount<'info, TokenAccount>>,
     pub wsol_vault: &'b Box<InterfaceAccount<'info, TokenAccount>>,
@@ -404,6 +594,8 @@ pub fn fund_creator_authority<'b, 'info>(
 ) -> Result<()> {
     let FundCreatorAuthorityAccounts {
         proposal,
+        proposal_escrow,
+        proposal_escrow_bump,
         wsol_vault,
         system_program,
         creator_authority,
@@ -415,17 +607,25 @@ pub fn fund_creator_authority<'b, 'info>(
 
     let signer_seeds: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
 
+    // Move this proposal's backing out of its own escrow
+    let proposal_key = proposal.key();
+    let escrow_signer_seeds: &[&[&[u8]]] = &[&[
+        PROPOSAL_ESCROW,
+        proposal_key.as_ref(),
+        &[proposal_escrow_bump],
+    ]];
+
     let program_id = system_program.to_account_info();
     let cpi_context = CpiContext::new_with_signer(
         program_id,
         Transfer {
-            from: creator_authority.to_account_info(),
+            from: proposal_escrow.to_account_info(),
             to: wsol_vault.to_account_info(),
         },
-        signer_seeds,
+        escrow_signer_seeds,
     );
 
-    transfer(cpi_context, proposal.total_backing)?;
+    transfer(cpi_context, proposal.effective_backing())?;
 
     let cpi_accounts = token::SyncNative {
         account: wsol_vault.to_account_info(),
@@ -0,0 +1,100 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+
+use crate::{
+    constant::{seeds::BACKER, ANCHOR_DISCRIMINATOR},
+    errors::ProposalError,
+    state::backers::Backers,
+};
+
+/// `Backers` layout before the fee checkpoints and excess refund field.
+#[derive(AnchorDeserialize, InitSpace)]
+struct LegacyBackers {
+    claim_amount: u64,
+    initial_airdrop_received: bool,
+    settle_cycle: u8,
+    claimed_upto: u8,
+    deposit_amount: u64,
+}
+
+// Permissionless: the new fields are always zero, so anyone may pay to unlock a backer
+#[derive(Accounts)]
+pub struct MigrateBacker<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: only used to derive the backer account
+    pub backer: UncheckedAccount<'info>,
+
+    /// CHECK: only used to derive the backer account
+    pub proposal: UncheckedAccount<'info>,
+
+    /// CHECK: legacy backer account, layout is checked in the handler
+    #[account(
+        mut,
+        seeds = [BACKER, proposal.key().as_ref(), backer.key().as_ref()],
+        bump,
+        owner = crate::ID @ ProposalError::IncorrectAccount,
+    )]
+    pub backer_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateBacker<'info> {
+    pub fn handle_migrate_backer(&mut self) -> Result<()> {
+        let info = self.backer_account.to_account_info();
+        require!(
+            info.data_len() == ANCHOR_DISCRIMINATOR + LegacyBackers::INIT_SPACE,
+            ProposalError::BackerAlreadyMigrated
+        );
+
+        let legacy = {
+            let data = info.try_borrow_data()?;
+            require!(
+                data[..ANCHOR_DISCRIMINATOR] == *Backers::DISCRIMINATOR,
+                ProposalError::IncorrectAccount
+            );
+            LegacyBackers::deserialize(&mut &data[ANCHOR_DISCRIMINATOR..])?
+        };
+
+        let migrated = Backers {
+            claim_amount: legacy.claim_amount,
+            initial_airdrop_received: legacy.initial_airdrop_received,
+            settle_cycle: legacy.settle_cycle,
+            claimed_upto: legacy.claimed_upto,
+            deposit_amount: legacy.deposit_amount,
+            // Legacy proposals start their fee indices at 0 when they are migrated
+            fee_checkpoint_token: 0,
+            fee_checkpoint_wsol: 0,
+            excess_refunded: 0,
+        };
+
+        // Grow the account to the current layout, topping up rent from the payer
+        let new_len = ANCHOR_DISCRIMINATOR + Backers::INIT_SPACE;
+        let rent_due = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(info.lamports());
+        if rent_due > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+        info.realloc(new_len, false)?;
+
+        let mut data = info.try_borrow_mut_data()?;
+        migrated.try_serialize(&mut &mut data[..])?;
+
+        Ok(())
+    }
+}
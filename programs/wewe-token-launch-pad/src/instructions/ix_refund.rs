@@ -1,9 +1,9 @@
 use crate::{
-    const_pda::const_authority::VAULT_BUMP,
     constant::{seeds::*, treasury},
     errors::ProposalError,
     event::BackerRefunded,
-    state::{backers::Backers, backer_proposal_count::BackerProposalCount, proposal::Proposal, config::Configs},
+    state::{backers::Backers, backer_proposal_count::BackerProposalCount, proposal::{Proposal, ProposalStatus}, config::Configs},
+    utils::{escrow::check_escrow_balance, pause::check_not_paused, refund_fee::split_refund},
 };
 use anchor_lang::prelude::*;
 
@@ -16,15 +16,13 @@ pub struct Refund<'info> {
     #[account(mut)]
     pub proposal: Account<'info, Proposal>,
 
-    /// CHECK: vault authority
+    /// CHECK: SOL escrow holding this proposal's backing
     #[account(
         mut,
-        seeds = [
-            VAULT_AUTHORITY.as_ref(),
-        ],
+        seeds = [PROPOSAL_ESCROW, proposal.key().as_ref()],
         bump,
     )]
-    pub vault_authority: SystemAccount<'info>,
+    pub proposal_escrow: SystemAccount<'info>,
 
     /// CHECK: WEWE treasury account
     #[account(
@@ -49,71 +47,58 @@ pub struct Refund<'info> {
     pub backer_proposal_count: Account<'info, BackerProposalCount>,
 
     pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
     pub config: Account<'info, Configs>,
 }
 
 impl<'info> Refund<'info> {
-    pub fn handle_refund(&mut self) -> Result<()> {
-        require!(self.proposal.is_rejected, ProposalError::BackingNotEnded);
-        
-        // Calculate what was actually deposited (amount minus the fee that was deducted)
-        // This matches what was added to total_backing in ix_back_token.rs
-        let deposited_amount = self.config.amount_to_raise_per_user;
-        
-        // Get refund fee basis points with validation
-        // If uninitialized (likely 0 or garbage), default to 0 to avoid overflow
-        let fee_bps = if self.config.refund_fee_basis_points > 10000 {
-            0u16 // Safety: if value is unreasonable, default to 0
-        } else {
-            self.config.refund_fee_basis_points
-        };
-        
-        // Get the actual vault balance and verify we have enough
-        // Note: The vault_authority account must maintain rent-exempt balance
-        // Solana will prevent transferring all lamports to keep account rent-exempt
-        let vault_balance = self.vault_authority.lamports();
-        let total_to_transfer = deposited_amount;
-        
-        // Ensure vault has sufficient balance (must be >= what we need to transfer)
-        // The actual requirement is that vault_balance >= total_to_transfer
-        // Solana will automatically ensure rent-exempt minimum remains
+    pub fn handle_refund(&mut self, bumps: &RefundBumps) -> Result<()> {
+        check_not_paused(&self.config, &self.proposal)?;
+
         require!(
-            vault_balance >= total_to_transfer,
-            ProposalError::NumericalOverflow
+            matches!(
+                self.proposal.status,
+                ProposalStatus::Rejected | ProposalStatus::Cancelled
+            ),
+            ProposalError::BackingNotEnded
         );
         
-        // Calculate refund amount and fee
-        // Fee is calculated as a percentage (from config) of the deposited amount
-        // Formula: fee = deposited_amount * fee_bps / 10000
-        //          refund_amount = deposited_amount - fee
-        const BASIS_POINTS: u128 = 10000;
-        let fee_bps_u128 = fee_bps as u128;
-        
-        // Calculate fee as percentage of deposited amount (using fee_bps from config)
-        let wewe_fee_u128 = (deposited_amount as u128)
-            .checked_mul(fee_bps_u128)
-            .and_then(|n| n.checked_div(BASIS_POINTS))
+        // Refund exactly what this backer deposited
+        // This matches what was added to total_backing in ix_back_token.rs
+        let deposited_amount = self.backer_account.deposit_amount;
+        // Excess already paid back through claim_excess is no longer in the escrow
+        let excess_refunded = self.backer_account.excess_refunded;
+        let refundable_amount = deposited_amount
+            .checked_sub(excess_refunded)
             .ok_or(ProposalError::NumericalOverflow)?;
         
-        let wewe_fee_to_collect: u64 = wewe_fee_u128
-            .try_into()
-            .map_err(|_| ProposalError::NumericalOverflow)?;
+        // The fee depends on why the proposal became refundable
+        let cause = self
+            .proposal
+            .rejection_cause
+            .ok_or(ProposalError::BackingNotEnded)?;
+        let fee_bps = self.proposal.terms.refund_fees.fee_bps(cause);
         
-        // Calculate refund amount as deposit minus fee
-        let refund_amount = deposited_amount
-            .checked_sub(wewe_fee_to_collect)
-            .ok_or(ProposalError::NumericalOverflow)?;
+        // The escrow only holds this proposal's backing, so one proposal's refunds
+        // can never be paid out of another proposal's deposits
+        check_escrow_balance(
+            &self.proposal_escrow.to_account_info(),
+            self.proposal.escrowed_backing(),
+        )?;
         
-        // Verify the math: refund + fee should equal deposited_amount
-        let total = refund_amount
-            .checked_add(wewe_fee_to_collect)
-            .ok_or(ProposalError::NumericalOverflow)?;
-        require!(
-            total == deposited_amount,
-            ProposalError::NumericalOverflow
-        );
+        // Calculate refund amount and fee
+        // Fee is calculated as a percentage (from config) of the deposited amount
+        let (refund_amount, wewe_fee_to_collect) = split_refund(refundable_amount, fee_bps)?;
 
-        let signer_seeds: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
+        let proposal_key = self.proposal.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            PROPOSAL_ESCROW,
+            proposal_key.as_ref(),
+            &[bumps.proposal_escrow],
+        ]];
 
         // Transfer WEWE fee to treasury
         if wewe_fee_to_collect > 0 {
@@ -121,7 +106,7 @@ impl<'info> Refund<'info> {
                 CpiContext::new_with_signer(
                     self.system_program.to_account_info(),
                     anchor_lang::system_program::Transfer {
-                        from: self.vault_authority.to_account_info(),
+                        from: self.proposal_escrow.to_account_info(),
                         to: self.wewe_treasury.to_account_info(),
                     },
                     signer_seeds,
@@ -135,7 +120,7 @@ impl<'info> Refund<'info> {
             CpiContext::new_with_signer(
                 self.system_program.to_account_info(),
                 anchor_lang::system_program::Transfer {
-                    from: self.vault_authority.to_account_info(),
+                    from: self.proposal_escrow.to_account_info(),
                     to: self.backer.to_account_info(),
                 },
                 signer_seeds,
@@ -144,7 +129,7 @@ impl<'info> Refund<'info> {
         )?;
 
         // Update total_backing to reflect the deposited amount being removed
-        // Note: total_backing tracks deposited_amount (amount_to_raise_per_user)
+        // Note: total_backing is the sum of every backer's deposit_amount
         let total_removed = deposited_amount;
         let old_total_backing = self.proposal.total_backing;
         
@@ -157,6 +142,16 @@ impl<'info> Refund<'info> {
         self.proposal.total_backing = old_total_backing
             .checked_sub(total_removed)
             .ok_or(ProposalError::NumericalOverflow)?;
+        self.proposal.excess_refunded = self
+            .proposal
+            .excess_refunded
+            .checked_sub(excess_refunded)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
+        check_escrow_balance(
+            &self.proposal_escrow.to_account_info(),
+            self.proposal.escrowed_backing(),
+        )?;
 
         // Decrement the backer's active proposal count
         if self.backer_proposal_count.active_count > 0 {
@@ -173,6 +168,7 @@ impl<'info> Refund<'info> {
             proposal_address: self.proposal.key(),
             refund_amount,
             wewe_fee: wewe_fee_to_collect,
+            cause,
         });
 
         Ok(())
@@ -0,0 +1,157 @@
+use crate::{
+    constant::{seeds::*, treasury},
+    errors::ProposalError,
+    event::BackerRefunded,
+    state::{
+        backer_proposal_count::BackerProposalCount,
+        backers::Backers,
+        config::Configs,
+        proposal::{Proposal, ProposalStatus, RejectionCause},
+    },
+    utils::{escrow::check_escrow_balance, pause::check_not_paused, refund_fee::split_refund},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct WithdrawBacking<'info> {
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    /// CHECK: SOL escrow holding this proposal's backing
+    #[account(
+        mut,
+        seeds = [PROPOSAL_ESCROW, proposal.key().as_ref()],
+        bump,
+    )]
+    pub proposal_escrow: SystemAccount<'info>,
+
+    /// CHECK: WEWE treasury account
+    #[account(
+        mut,
+        address = treasury::ID,
+    )]
+    pub wewe_treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [BACKER, proposal.key().as_ref(), backer.key().as_ref()],
+        bump,
+        close = backer,
+    )]
+    pub backer_account: Account<'info, Backers>,
+
+    #[account(
+        mut,
+        seeds = [BACKER_PROPOSAL_COUNT, backer.key().as_ref()],
+        bump,
+    )]
+    pub backer_proposal_count: Account<'info, BackerProposalCount>,
+
+    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Configs>,
+}
+
+impl<'info> WithdrawBacking<'info> {
+    pub fn handle_withdraw_backing(&mut self, bumps: &WithdrawBackingBumps) -> Result<()> {
+        check_not_paused(&self.config, &self.proposal)?;
+
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            self.proposal.status == ProposalStatus::Backing,
+            ProposalError::BackingEnded
+        );
+        require!(
+            now <= self.proposal.backing_ends_at,
+            ProposalError::BackingEnded
+        );
+        // Keep a raise from collapsing at the last second
+        require!(
+            now < self
+                .proposal
+                .backing_ends_at
+                .saturating_sub(self.proposal.terms.withdrawal_lockout_seconds),
+            ProposalError::WithdrawalLocked
+        );
+
+        let deposited_amount = self.backer_account.deposit_amount;
+        let cause = RejectionCause::BackerExit;
+        let (refund_amount, wewe_fee_to_collect) =
+            split_refund(deposited_amount, self.proposal.terms.refund_fees.fee_bps(cause))?;
+
+        let proposal_key = self.proposal.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            PROPOSAL_ESCROW,
+            proposal_key.as_ref(),
+            &[bumps.proposal_escrow],
+        ]];
+
+        // Transfer early-exit fee to treasury
+        if wewe_fee_to_collect > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: self.proposal_escrow.to_account_info(),
+                        to: self.wewe_treasury.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                wewe_fee_to_collect,
+            )?;
+        }
+
+        // Transfer remaining SOL to backer
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: self.proposal_escrow.to_account_info(),
+                    to: self.backer.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            refund_amount,
+        )?;
+
+        self.proposal.total_backing = self
+            .proposal
+            .total_backing
+            .checked_sub(deposited_amount)
+            .ok_or(ProposalError::NumericalOverflow)?;
+        self.proposal.total_backers = self
+            .proposal
+            .total_backers
+            .checked_sub(1)
+            .ok_or(ProposalError::NumericalOverflow)?;
+
+        check_escrow_balance(
+            &self.proposal_escrow.to_account_info(),
+            self.proposal.total_backing,
+        )?;
+
+        // Free the slot so the backer can back another proposal
+        self.backer_proposal_count.active_count = self
+            .backer_proposal_count
+            .active_count
+            .saturating_sub(1);
+
+        emit!(BackerRefunded {
+            backer: self.backer.key(),
+            backer_account: self.backer_account.key(),
+            proposal_address: self.proposal.key(),
+            refund_amount,
+            wewe_fee: wewe_fee_to_collect,
+            cause,
+        });
+
+        Ok(())
+    }
+}
@@ -4,6 +4,15 @@ pub mod ix_create_proposal;
 pub mod ix_refund;
 pub mod ix_claim_tokens;
 pub mod ix_airdrop;
+pub mod ix_finalize_proposal;
+pub mod ix_cancel_proposal;
+pub mod ix_withdraw_backing;
+pub mod ix_close_backer;
+pub mod ix_close_proposal;
+pub mod ix_claim_backer_fees;
+pub mod ix_claim_excess;
+pub mod ix_migrate_backer;
+pub mod ix_close_backer_fee_vault;
 pub mod admin;
 
 pub use admin::*;
@@ -12,4 +21,13 @@ pub use ix_launch_pool::*;
 pub use ix_create_proposal::*;
 pub use ix_refund::*;
 pub use ix_claim_tokens::*;
-pub use ix_airdrop::*;
\ No newline at end of file
+pub use ix_airdrop::*;
+pub use ix_finalize_proposal::*;
+pub use ix_cancel_proposal::*;
+pub use ix_withdraw_backing::*;
+pub use ix_close_backer::*;
+pub use ix_close_proposal::*;
+pub use ix_claim_backer_fees::*;
+pub use ix_claim_excess::*;
+pub use ix_migrate_backer::*;
+pub use ix_close_backer_fee_vault::*;
\ No newline at end of file
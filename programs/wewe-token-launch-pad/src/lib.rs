@@ -11,6 +11,11 @@ mod utils;
 
 use errors::*;
 use instructions::*;
+use state::{
+    admin_registry::AdminRole,
+    config::{AllocationMode, PoolFeeSplit, ProposalTerms, RefundFeeSchedule},
+    config_update::ConfigParam,
+};
 use utils::*;
 
 declare_id!("DiBfVGvJ1c5ZrE1AcCpoiZYfVkPdaUtsjrUWEhwsJs9X");
@@ -18,83 +23,207 @@ declare_id!("DiBfVGvJ1c5ZrE1AcCpoiZYfVkPdaUtsjrUWEhwsJs9X");
 pub mod wewe_token_launch_pad {
     use super::*;
 
-    #[access_control(check_configure_authority(&ctx.accounts.authority))]
+    pub fn init_admin_registry(
+        ctx: Context<InitAdminRegistry>,
+        operator: Pubkey,
+        pauser: Pubkey,
+        treasury_manager: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts
+            .handle_init_admin_registry(operator, pauser, treasury_manager, &ctx.bumps)
+    }
+
+    pub fn propose_role_transfer(
+        ctx: Context<ProposeRoleTransfer>,
+        role: AdminRole,
+        new_holder: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.handle_propose_role_transfer(role, new_holder)
+    }
+
+    pub fn accept_role_transfer(ctx: Context<AcceptRoleTransfer>, role: AdminRole) -> Result<()> {
+        ctx.accounts.handle_accept_role_transfer(role)
+    }
+
+    #[access_control(check_pauser(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        ctx.accounts.handle_set_paused(paused)
+    }
+
+    #[access_control(check_pauser(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
+    pub fn set_proposal_frozen(ctx: Context<SetProposalFrozen>, frozen: bool) -> Result<()> {
+        ctx.accounts.handle_set_proposal_frozen(frozen)
+    }
+
+    #[access_control(check_configure_authority(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
     pub fn set_config(
         ctx: Context<SetConfig>,
-        amount_to_raise_per_user: u64,
+        min_amount_per_user: u64,
+        max_amount_per_user: u64,
         total_mint: u64,
         total_pool_tokens: u64,
         maker_token_amount: u64,
         total_airdrop_amount_per_milestone: u64,
         min_backers: u64,
         max_backed_proposals: u64,
-        refund_fee_basis_points: u16,
+        refund_fees: RefundFeeSchedule,
+        allocation_mode: AllocationMode,
+        min_backing_duration: i64,
+        max_backing_duration: i64,
+        withdrawal_lockout_seconds: i64,
+        sqrt_price_tolerance_bps: u16,
+        config_update_delay: i64,
     ) -> Result<()> {
         ctx.accounts.handle_set_config(
-            amount_to_raise_per_user,
+            min_amount_per_user,
+            max_amount_per_user,
             total_mint,
             total_pool_tokens,
             maker_token_amount,
             total_airdrop_amount_per_milestone,
             min_backers,
             max_backed_proposals,
-            refund_fee_basis_points,
+            refund_fees,
+            allocation_mode,
+            min_backing_duration,
+            max_backing_duration,
+            withdrawal_lockout_seconds,
+            sqrt_price_tolerance_bps,
+            config_update_delay,
         )?;
         Ok(())
     }
 
+    #[access_control(check_configure_authority(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
+    pub fn migrate_config(
+        ctx: Context<MigrateConfig>,
+        sqrt_price_tolerance_bps: u16,
+        config_update_delay: i64,
+        terms: Option<ProposalTerms>,
+    ) -> Result<()> {
+        ctx.accounts
+            .handle_migrate_config(sqrt_price_tolerance_bps, config_update_delay, terms)
+    }
+
+    #[access_control(check_configure_authority(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
+    pub fn create_launch_template(
+        ctx: Context<CreateLaunchTemplate>,
+        name: String,
+        terms: ProposalTerms,
+        allocation_mode: AllocationMode,
+        pool_fee_split: Option<PoolFeeSplit>,
+    ) -> Result<()> {
+        ctx.accounts.handle_create_launch_template(
+            name,
+            terms,
+            allocation_mode,
+            pool_fee_split,
+            &ctx.bumps,
+        )
+    }
+
+    #[access_control(check_configure_authority(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
+    pub fn retire_launch_template(ctx: Context<RetireLaunchTemplate>) -> Result<()> {
+        ctx.accounts.handle_retire_launch_template()
+    }
+
+    #[access_control(check_config_update_authority(&ctx.accounts.authority, &ctx.accounts.admin_registry, &param))]
+    pub fn queue_config_update(ctx: Context<QueueConfigUpdate>, param: ConfigParam) -> Result<()> {
+        ctx.accounts.handle_queue_config_update(param, &ctx.bumps)
+    }
+
+    #[access_control(check_config_update_authority(&ctx.accounts.authority, &ctx.accounts.admin_registry, &ctx.accounts.pending_update.param))]
+    pub fn execute_config_update(ctx: Context<ExecuteConfigUpdate>) -> Result<()> {
+        ctx.accounts.handle_execute_config_update()
+    }
+
+    #[access_control(check_config_update_authority(&ctx.accounts.authority, &ctx.accounts.admin_registry, &ctx.accounts.pending_update.param))]
+    pub fn cancel_config_update(ctx: Context<CancelConfigUpdate>) -> Result<()> {
+        ctx.accounts.handle_cancel_config_update()
+    }
+
     pub fn create_proposal(
         ctx: Context<CreateProposal>,
         token_name: String,
         token_symbol: String,
         token_uri: String,
+        backing_duration: i64,
+        pool_fee_split: Option<PoolFeeSplit>,
     ) -> Result<()> {
-        ctx.accounts.handle_create_proposal(token_name, token_symbol, token_uri, &ctx.bumps)
+        ctx.accounts.handle_create_proposal(
+            token_name,
+            token_symbol,
+            token_uri,
+            backing_duration,
+            pool_fee_split,
+            &ctx.bumps,
+        )
     }
 
-    pub fn deposit_sol(ctx: Context<Contribute>) -> Result<()> {
-        ctx.accounts.handle_deposit_sol()
+    pub fn deposit_sol(ctx: Context<Contribute>, amount: u64) -> Result<()> {
+        ctx.accounts.handle_deposit_sol(amount)
     }
 
     pub fn refund(ctx: Context<Refund>) -> Result<()> {
-        ctx.accounts.handle_refund()
+        ctx.accounts.handle_refund(&ctx.bumps)
+    }
+
+    pub fn withdraw_backing(ctx: Context<WithdrawBacking>) -> Result<()> {
+        ctx.accounts.handle_withdraw_backing(&ctx.bumps)
     }
 
-    pub fn create_pool(ctx: Context<DammV2>, sqrt_price: u128) -> Result<()> {
-        ctx.accounts.handle_create_pool(sqrt_price)
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        ctx.accounts.handle_finalize_proposal()
+    }
+
+    pub fn cancel_proposal(ctx: Context<CancelProposal>, reason: u8) -> Result<()> {
+        ctx.accounts.handle_cancel_proposal(reason)
+    }
+
+    pub fn create_pool(
+        ctx: Context<DammV2>,
+        sqrt_price: u128,
+        activation_delay: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts
+            .handle_create_pool(sqrt_price, activation_delay, &ctx.bumps)
     }
 
     pub fn claim_milestone_reward(ctx: Context<Claim>) -> Result<()> {
         ctx.accounts.handler_claim_milestone_reward()
     }
 
-    #[access_control(check(&ctx.accounts.authority))]
+    #[access_control(check(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
     pub fn reject_proposal(ctx: Context<RejectProposal>) -> Result<()> {
         ctx.accounts.handle_reject_proposal()
     }
 
     // TODO: Figure out if this is needed
-    // #[access_control(check(&ctx.accounts.authority))]
+    // #[access_control(check(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
     // pub fn emergency_unlock(ctx: Context<EmergencyUnlock>) -> Result<()> {
     //     ctx.accounts.handle_emergency_unlock()
     // }
 
-    #[access_control(check(&ctx.accounts.authority))]
+    #[access_control(check(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
     pub fn snapshot_backer_amount(ctx: Context<SnapshotBacker>) -> Result<()> {
         ctx.accounts.handle_snapshot()
     }
 
-    #[access_control(check(&ctx.accounts.authority))]
+    #[access_control(check(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
     pub fn mint_soulbound_to_user(ctx: Context<MintSoulboundToUser>) -> Result<()> {
         ctx.accounts.handle_mint_soulbound_to_user(&ctx.bumps)
     }
 
-    #[access_control(check(&ctx.accounts.payer))]
+    #[access_control(check(&ctx.accounts.payer, &ctx.accounts.admin_registry))]
     pub fn claim_pool_fee(ctx: Context<ClaimPositionFee>) -> Result<()> {
         ctx.accounts.handle_claim_position_fee()
     }
 
-    #[access_control(check(&ctx.accounts.authority))]
+    pub fn claim_backer_fees(ctx: Context<ClaimBackerFees>) -> Result<()> {
+        ctx.accounts.handle_claim_backer_fees()
+    }
+
+    #[access_control(check(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
     pub fn initialise_milestone(ctx: Context<InitialiseMilestone>) -> Result<()> {
         ctx.accounts.handle_initialise_milestone()
     }
@@ -103,19 +232,54 @@ pub mod wewe_token_launch_pad {
         ctx.accounts.handle_airdrop()
     }
 
-    #[access_control(check(&ctx.accounts.authority))]
+    #[access_control(check(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
     pub fn end_milestone(ctx: Context<EndMilestone>) -> Result<()> {
         ctx.accounts.handle_end_milestone()
     }
 
-    #[access_control(check(&ctx.accounts.authority))]
+    #[access_control(check(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
     pub fn reset_pool_launch(ctx: Context<ResetPoolLaunch>) -> Result<()> {
         ctx.accounts.handle_reset_pool_launch()
     }
 
-    #[access_control(check(&ctx.accounts.authority))]
+    #[access_control(check(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
     pub fn reset_airdrop(ctx: Context<ResetAirdrop>) -> Result<()> {
         ctx.accounts.handle_reset_airdrop()
     }
 
+    #[access_control(check(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
+    pub fn migrate_proposal(
+        ctx: Context<MigrateProposal>,
+        pool_address: Option<Pubkey>,
+        pool_position: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts
+            .handle_migrate_proposal(pool_address, pool_position)
+    }
+
+    pub fn migrate_backer(ctx: Context<MigrateBacker>) -> Result<()> {
+        ctx.accounts.handle_migrate_backer()
+    }
+
+    #[access_control(check(&ctx.accounts.authority, &ctx.accounts.admin_registry))]
+    pub fn complete_proposal(ctx: Context<CompleteProposal>) -> Result<()> {
+        ctx.accounts.handle_complete_proposal()
+    }
+
+    pub fn claim_excess(ctx: Context<ClaimExcess>) -> Result<()> {
+        ctx.accounts.handle_claim_excess(&ctx.bumps)
+    }
+
+    pub fn close_backer(ctx: Context<CloseBacker>) -> Result<()> {
+        ctx.accounts.handle_close_backer()
+    }
+
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        ctx.accounts.handle_close_proposal(&ctx.bumps)
+    }
+
+    pub fn close_backer_fee_vault(ctx: Context<CloseBackerFeeVault>) -> Result<()> {
+        ctx.accounts.handle_close_backer_fee_vault()
+    }
+
 }
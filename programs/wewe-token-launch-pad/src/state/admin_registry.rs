@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/// On-chain holders of the privileged roles. Each role moves in two steps:
+/// the holder (or the configurator) proposes a new key, then that key accepts.
+#[account]
+#[derive(InitSpace)]
+pub struct AdminRegistry {
+    pub configurator: Pubkey,     // set_config and role recovery
+    pub operator: Pubkey,         // day-to-day chain service instructions
+    pub pauser: Pubkey,
+    pub treasury_manager: Pubkey,
+    pub pending_configurator: Option<Pubkey>,
+    pub pending_operator: Option<Pubkey>,
+    pub pending_pauser: Option<Pubkey>,
+    pub pending_treasury_manager: Option<Pubkey>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AdminRole {
+    Configurator,
+    Operator,
+    Pauser,
+    TreasuryManager,
+}
+
+impl AdminRegistry {
+    pub fn holder(&self, role: AdminRole) -> Pubkey {
+        match role {
+            AdminRole::Configurator => self.configurator,
+            AdminRole::Operator => self.operator,
+            AdminRole::Pauser => self.pauser,
+            AdminRole::TreasuryManager => self.treasury_manager,
+        }
+    }
+
+    pub fn holder_mut(&mut self, role: AdminRole) -> &mut Pubkey {
+        match role {
+            AdminRole::Configurator => &mut self.configurator,
+            AdminRole::Operator => &mut self.operator,
+            AdminRole::Pauser => &mut self.pauser,
+            AdminRole::TreasuryManager => &mut self.treasury_manager,
+        }
+    }
+
+    pub fn pending_mut(&mut self, role: AdminRole) -> &mut Option<Pubkey> {
+        match role {
+            AdminRole::Configurator => &mut self.pending_configurator,
+            AdminRole::Operator => &mut self.pending_operator,
+            AdminRole::Pauser => &mut self.pending_pauser,
+            AdminRole::TreasuryManager => &mut self.pending_treasury_manager,
+        }
+    }
+}
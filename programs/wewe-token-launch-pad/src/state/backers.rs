@@ -8,4 +8,7 @@ pub struct Backers {
     pub settle_cycle: u8,
     pub claimed_upto: u8,
     pub deposit_amount: u64,
+    pub fee_checkpoint_token: u128, // Proposal::backer_fee_index_token at the last fee claim
+    pub fee_checkpoint_wsol: u128,  // Proposal::backer_fee_index_wsol at the last fee claim
+    pub excess_refunded: u64,       // Oversubscribed part of deposit_amount already paid back
 }
@@ -1,15 +1,439 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    constant::{
+        MAX_CONFIG_UPDATE_DELAY,
+        pool_activation_bounds::{MAX_ACTIVATION_SLOT_DURATION, MAX_ACTIVATION_TIME_DURATION},
+        pool_fee_bounds::{
+            BASIS_POINT_MAX, BIN_STEP_BPS_DEFAULT, BIN_STEP_BPS_U128_DEFAULT, MAX_FEE_NUMERATOR,
+            MAX_VARIABLE_FEE_CONTROL, MAX_VOLATILITY_ACCUMULATOR, MIN_FEE_NUMERATOR,
+        },
+    },
+    state::proposal::RejectionCause,
+};
+
 #[account]
 #[derive(InitSpace)]
 
 pub struct Configs {
-    pub amount_to_raise_per_user : u64,
+    pub min_amount_per_user : u64,
+    pub max_amount_per_user : u64,
     pub total_mint : u64,
     pub total_pool_tokens: u64,
     pub maker_token_amount : u64,
     pub total_airdrop_amount_per_milestone: u64,
     pub min_backers : u64,
     pub max_backed_proposals : u64,
-    pub refund_fee_basis_points : u16, // Fee percentage in basis points (100 = 1%, 250 = 2.5%, etc.)
-}
\ No newline at end of file
+    pub refund_fees : RefundFeeSchedule,
+    pub allocation_mode : AllocationMode,
+    pub min_backing_duration : i64, // Shortest backing window a maker may choose, in seconds
+    pub max_backing_duration : i64, // Longest backing window a maker may choose, in seconds
+    pub withdrawal_lockout_seconds : i64, // Backers cannot withdraw within this many seconds of the deadline
+    pub sqrt_price_tolerance_bps : u16, // How far create_pool's sqrt_price may stray from the raise-derived price
+    pub paused : bool, // Set by the pauser; halts every user-facing instruction
+    pub treasury : Pubkey, // Receives the treasury share of pool fees
+    pub pool_fee_split : PoolFeeSplit, // Default split of claimed pool fees
+    pub pool_fee_schedule : PoolFeeSchedule, // Fee curve every new DAMM v2 pool is created with
+    pub pool_activation : PoolActivation, // When trading opens on a newly created pool
+    pub pool_price_range : PoolPriceRange, // Price range the launch position covers
+    pub residual_policy : ResidualPolicy, // What happens to base tokens the launch position did not take
+    pub residual_quote_policy : ResidualQuotePolicy, // What happens to WSOL the launch position did not take
+    pub config_version : u64, // Bumped every time a queued update is applied
+    pub config_update_delay : i64, // Seconds a queued update waits before it can be executed
+    pub next_config_update_id : u64, // Seed of the next PendingConfigUpdate
+    pub launch_template_count : u64, // Seed of the next LaunchTemplate
+    pub max_backers : u64, // Most backers a single proposal accepts
+    pub soft_cap : u64, // Lamports a raise needs to launch; 0 leaves only min_backers
+    pub hard_cap : u64, // Most lamports a raise accepts; 0 for no cap
+    pub oversubscription : bool, // Accept deposits past the hard cap and scale everyone down to it
+}
+
+impl Configs {
+    /// Invariants every config must hold, both at init and after each queued update.
+    pub fn is_valid(&self) -> bool {
+        self.proposal_terms().is_valid()
+            && self.min_backing_duration > 0
+            && self.min_backing_duration <= self.max_backing_duration
+            && self.sqrt_price_tolerance_bps as u64 <= BASIS_POINT_MAX
+            && (0..=MAX_CONFIG_UPDATE_DELAY).contains(&self.config_update_delay)
+            && self.treasury != Pubkey::default()
+            && self.pool_fee_split.is_valid()
+            && self.pool_fee_schedule.is_valid()
+            && self.pool_activation.is_valid()
+            && self.pool_price_range.is_valid()
+    }
+
+    /// Economic terms a new proposal is created under.
+    pub fn proposal_terms(&self) -> ProposalTerms {
+        ProposalTerms {
+            min_amount_per_user: self.min_amount_per_user,
+            max_amount_per_user: self.max_amount_per_user,
+            total_mint: self.total_mint,
+            total_pool_tokens: self.total_pool_tokens,
+            maker_token_amount: self.maker_token_amount,
+            total_airdrop_amount_per_milestone: self.total_airdrop_amount_per_milestone,
+            min_backers: self.min_backers,
+            max_backers: self.max_backers,
+            soft_cap: self.soft_cap,
+            hard_cap: self.hard_cap,
+            oversubscription: self.oversubscription,
+            refund_fees: self.refund_fees,
+            withdrawal_lockout_seconds: self.withdrawal_lockout_seconds,
+        }
+    }
+
+    /// Pool launch settings a new proposal is created under.
+    pub fn pool_launch_terms(&self) -> PoolLaunchTerms {
+        PoolLaunchTerms {
+            sqrt_price_tolerance_bps: self.sqrt_price_tolerance_bps,
+            pool_fee_schedule: self.pool_fee_schedule,
+            pool_activation: self.pool_activation,
+            pool_price_range: self.pool_price_range,
+            residual_policy: self.residual_policy,
+            residual_quote_policy: self.residual_quote_policy,
+        }
+    }
+
+    /// Overwrites the economics `proposal_terms` reads.
+    pub fn set_proposal_terms(&mut self, terms: ProposalTerms) {
+        self.min_amount_per_user = terms.min_amount_per_user;
+        self.max_amount_per_user = terms.max_amount_per_user;
+        self.total_mint = terms.total_mint;
+        self.total_pool_tokens = terms.total_pool_tokens;
+        self.maker_token_amount = terms.maker_token_amount;
+        self.total_airdrop_amount_per_milestone = terms.total_airdrop_amount_per_milestone;
+        self.min_backers = terms.min_backers;
+        self.max_backers = terms.max_backers;
+        self.soft_cap = terms.soft_cap;
+        self.hard_cap = terms.hard_cap;
+        self.oversubscription = terms.oversubscription;
+        self.refund_fees = terms.refund_fees;
+        self.withdrawal_lockout_seconds = terms.withdrawal_lockout_seconds;
+    }
+}
+
+/// Snapshot of the `Configs` economics taken when a proposal is created, so a later
+/// `set_config` cannot change the terms its backers signed up for.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct ProposalTerms {
+    pub min_amount_per_user: u64,
+    pub max_amount_per_user: u64,
+    pub total_mint: u64,
+    pub total_pool_tokens: u64,
+    pub maker_token_amount: u64,
+    pub total_airdrop_amount_per_milestone: u64,
+    pub min_backers: u64,
+    pub max_backers: u64,
+    pub soft_cap: u64,
+    pub hard_cap: u64,
+    pub oversubscription: bool, // hard_cap is the raise target rather than a deposit limit
+    pub refund_fees: RefundFeeSchedule,
+    pub withdrawal_lockout_seconds: i64,
+}
+
+impl ProposalTerms {
+    pub fn is_valid(&self) -> bool {
+        // Supply has to cover the pool, the maker, the launch airdrop and at least one milestone
+        let supply_valid = self
+            .total_airdrop_amount_per_milestone
+            .checked_mul(2)
+            .and_then(|reserve| reserve.checked_add(self.total_pool_tokens))
+            .and_then(|allocated| allocated.checked_add(self.maker_token_amount))
+            .is_some_and(|allocated| allocated <= self.total_mint);
+
+        let caps_valid = self.hard_cap == 0
+            || (self.soft_cap <= self.hard_cap && self.min_amount_per_user <= self.hard_cap);
+        // Oversubscription scales deposits down to the hard cap, so it needs one
+        let oversubscription_valid = !self.oversubscription || self.hard_cap > 0;
+
+        self.min_amount_per_user > 0
+            && self.min_amount_per_user <= self.max_amount_per_user
+            && self.total_pool_tokens > 0
+            && supply_valid
+            && self.max_backers > 0
+            && self.min_backers <= self.max_backers
+            && caps_valid
+            && oversubscription_valid
+            && self.refund_fees.is_valid()
+            && self.withdrawal_lockout_seconds >= 0
+    }
+}
+
+/// Snapshot of the `Configs` pool launch settings taken when a proposal is created, so the
+/// pool is launched the way it was when backers committed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct PoolLaunchTerms {
+    pub sqrt_price_tolerance_bps: u16,
+    pub pool_fee_schedule: PoolFeeSchedule,
+    pub pool_activation: PoolActivation,
+    pub pool_price_range: PoolPriceRange,
+    pub residual_policy: ResidualPolicy,
+    pub residual_quote_policy: ResidualQuotePolicy,
+}
+
+/// How the airdrop and each milestone pool is split between backers.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AllocationMode {
+    /// Every backer gets `pool / total_backers`.
+    Equal,
+    /// Every backer gets `pool * deposit_amount / total_backing`.
+    DepositWeighted,
+}
+
+/// Refund fee per rejection cause, in basis points (100 = 1%, 250 = 2.5%, etc.).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct RefundFeeSchedule {
+    pub backer_exit_bps: u16,
+    pub platform_rejected_bps: u16,
+    pub failed_raise_bps: u16,
+    pub maker_cancelled_bps: u16,
+}
+
+impl RefundFeeSchedule {
+    pub fn fee_bps(&self, cause: RejectionCause) -> u16 {
+        match cause {
+            RejectionCause::BackerExit => self.backer_exit_bps,
+            RejectionCause::PlatformRejected => self.platform_rejected_bps,
+            RejectionCause::FailedRaise => self.failed_raise_bps,
+            RejectionCause::MakerCancelled => self.maker_cancelled_bps,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        [
+            self.backer_exit_bps,
+            self.platform_rejected_bps,
+            self.failed_raise_bps,
+            self.maker_cancelled_bps,
+        ]
+        .iter()
+        .all(|bps| *bps <= 10_000)
+    }
+}
+
+/// Split of claimed DAMM v2 pool fees, in basis points. The three shares add up to 10_000.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct PoolFeeSplit {
+    pub maker_bps: u16,
+    pub treasury_bps: u16,
+    pub backer_bps: u16,
+}
+
+impl PoolFeeSplit {
+    /// The split used before it became configurable: half to the maker, half to the treasury.
+    pub const DEFAULT: Self = Self {
+        maker_bps: 5_000,
+        treasury_bps: 5_000,
+        backer_bps: 0,
+    };
+
+    pub fn is_valid(&self) -> bool {
+        self.maker_bps as u32 + self.treasury_bps as u32 + self.backer_bps as u32 == 10_000
+    }
+
+    /// A per-proposal override may only give away part of the maker's share,
+    /// never the treasury's or the backers'.
+    pub fn is_valid_override_of(&self, default: &PoolFeeSplit) -> bool {
+        self.is_valid()
+            && self.treasury_bps >= default.treasury_bps
+            && self.backer_bps >= default.backer_bps
+    }
+}
+
+/// Shape of the base fee decay, mirroring DAMM v2's `BaseFeeMode`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum FeeSchedulerMode {
+    /// Fee drops by `reduction_factor` (a fee numerator) every period.
+    Linear,
+    /// Fee drops by `reduction_factor` basis points of the current fee every period.
+    Exponential,
+}
+
+/// DAMM v2 dynamic (volatility) fee parameters.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct DynamicFeeSchedule {
+    pub bin_step: u16,
+    pub bin_step_u128: u128,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub max_volatility_accumulator: u32,
+    pub variable_fee_control: u32,
+}
+
+/// Anti-sniper fee curve passed to DAMM v2 when a pool is created.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct PoolFeeSchedule {
+    pub cliff_fee_numerator: u64,
+    pub scheduler_mode: FeeSchedulerMode,
+    pub number_of_periods: u16,
+    pub period_frequency: u64,
+    pub reduction_factor: u64,
+    pub dynamic_fee: Option<DynamicFeeSchedule>,
+    pub collect_fee_mode: u8,
+}
+
+impl PoolFeeSchedule {
+    /// The curve pools were launched with before it became configurable.
+    pub const DEFAULT: Self = Self {
+        cliff_fee_numerator: 990_000_000,
+        scheduler_mode: FeeSchedulerMode::Linear,
+        number_of_periods: 100,
+        period_frequency: 1,
+        reduction_factor: 9_700_000,
+        dynamic_fee: None,
+        collect_fee_mode: 1,
+    };
+
+    /// Fee numerator once every period has elapsed.
+    pub fn ending_fee_numerator(&self) -> Option<u64> {
+        match self.scheduler_mode {
+            FeeSchedulerMode::Linear => self.cliff_fee_numerator.checked_sub(
+                self.reduction_factor
+                    .checked_mul(self.number_of_periods as u64)?,
+            ),
+            // cliff * (1 - reduction_factor / BASIS_POINT_MAX) ^ number_of_periods, computed
+            // the way DAMM v2 does it: in Q64.64, rounding down after every multiplication
+            FeeSchedulerMode::Exponential => {
+                if self.reduction_factor >= BASIS_POINT_MAX {
+                    return None;
+                }
+                let reduction = ((self.reduction_factor as u128) << 64) / BASIS_POINT_MAX as u128;
+                let factor = pow_q64((1u128 << 64) - reduction, self.number_of_periods)?;
+                let fee = factor.checked_mul(self.cliff_fee_numerator as u128)? >> 64;
+                u64::try_from(fee).ok()
+            }
+        }
+    }
+
+    /// Mirrors the checks DAMM v2 runs on `PoolFeeParameters`, so a schedule that passes here
+    /// cannot fail pool creation.
+    pub fn is_valid(&self) -> bool {
+        let fee_in_bounds = |fee: u64| (MIN_FEE_NUMERATOR..=MAX_FEE_NUMERATOR).contains(&fee);
+
+        // The scheduler is either off entirely or has every factor set
+        let scheduler_valid = (self.number_of_periods == 0
+            && self.period_frequency == 0
+            && self.reduction_factor == 0)
+            || (self.number_of_periods > 0 && self.period_frequency > 0 && self.reduction_factor > 0);
+        let base_fee_valid = fee_in_bounds(self.cliff_fee_numerator)
+            && scheduler_valid
+            && self.ending_fee_numerator().is_some_and(fee_in_bounds);
+
+        // 0 = both tokens, 1 = quote token only
+        let collect_mode_valid = self.collect_fee_mode <= 1;
+
+        let dynamic_fee_valid = match self.dynamic_fee {
+            Some(dynamic) => {
+                dynamic.bin_step == BIN_STEP_BPS_DEFAULT
+                    && dynamic.bin_step_u128 == BIN_STEP_BPS_U128_DEFAULT
+                    && dynamic.filter_period < dynamic.decay_period
+                    && dynamic.reduction_factor as u64 <= BASIS_POINT_MAX
+                    && dynamic.variable_fee_control <= MAX_VARIABLE_FEE_CONTROL
+                    && dynamic.max_volatility_accumulator <= MAX_VOLATILITY_ACCUMULATOR
+            }
+            None => true,
+        };
+
+        base_fee_valid && collect_mode_valid && dynamic_fee_valid
+    }
+}
+
+/// `base ^ exp` for a Q64.64 `base`, by squaring; `exp` is at most 16 bits.
+fn pow_q64(mut base: u128, mut exp: u16) -> Option<u128> {
+    let mut result = 1u128 << 64;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result.checked_mul(base)? >> 64;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = base.checked_mul(base)? >> 64;
+        }
+    }
+    Some(result)
+}
+
+/// Clock a pool's activation point is measured in, mirroring DAMM v2's `ActivationType`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ActivationType {
+    Slot,
+    Timestamp,
+}
+
+impl ActivationType {
+    /// Value DAMM v2 expects in `activation_type`.
+    pub fn to_damm(self) -> u8 {
+        match self {
+            ActivationType::Slot => 0,
+            ActivationType::Timestamp => 1,
+        }
+    }
+}
+
+/// Delay between pool creation and the start of trading, so the airdrop can land first.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct PoolActivation {
+    pub activation_type: ActivationType,
+    pub default_delay: u64, // Used when create_pool is not given a delay; 0 activates immediately
+    pub max_delay: u64,     // Upper bound for a delay passed to create_pool
+}
+
+impl PoolActivation {
+    /// Pools open for trading as soon as they are created.
+    pub const DEFAULT: Self = Self {
+        activation_type: ActivationType::Timestamp,
+        default_delay: 0,
+        max_delay: 0,
+    };
+
+    pub fn is_valid(&self) -> bool {
+        let limit = match self.activation_type {
+            ActivationType::Slot => MAX_ACTIVATION_SLOT_DURATION,
+            ActivationType::Timestamp => MAX_ACTIVATION_TIME_DURATION,
+        };
+
+        self.default_delay <= self.max_delay && self.max_delay <= limit
+    }
+}
+
+/// Price range of the launch position, as multiples of the initial price in basis points
+/// (5_000 = half the initial price, 40_000 = four times it). 0 leaves that side unbounded.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct PoolPriceRange {
+    pub min_price_bps: u32,
+    pub max_price_bps: u32,
+}
+
+impl PoolPriceRange {
+    /// The full MIN_SQRT_PRICE..MAX_SQRT_PRICE range pools used before.
+    pub const DEFAULT: Self = Self {
+        min_price_bps: 0,
+        max_price_bps: 0,
+    };
+
+    pub fn is_valid(&self) -> bool {
+        let min_valid = self.min_price_bps == 0 || (self.min_price_bps as u64) < BASIS_POINT_MAX;
+        let max_valid = self.max_price_bps == 0 || (self.max_price_bps as u64) > BASIS_POINT_MAX;
+        min_valid && max_valid
+    }
+}
+
+/// Where base tokens left over after the launch position is opened end up.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ResidualPolicy {
+    /// Burn the base tokens.
+    Burn,
+    /// Send the base tokens to the treasury's token account.
+    Treasury,
+}
+
+/// Where WSOL left over after the launch position is opened ends up. It is always
+/// unwrapped first, since lamports cannot be burned as a token.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ResidualQuotePolicy {
+    /// Send the lamports to the incinerator.
+    Burn,
+    /// Send the lamports to the treasury wallet.
+    Treasury,
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    admin_registry::AdminRole,
+    config::{
+        AllocationMode, Configs, PoolActivation, PoolFeeSchedule, PoolFeeSplit, PoolPriceRange,
+        RefundFeeSchedule, ResidualPolicy, ResidualQuotePolicy,
+    },
+};
+
+/// A single `Configs` field together with its value.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ConfigParam {
+    MinAmountPerUser(u64),
+    MaxAmountPerUser(u64),
+    TotalMint(u64),
+    TotalPoolTokens(u64),
+    MakerTokenAmount(u64),
+    TotalAirdropAmountPerMilestone(u64),
+    MinBackers(u64),
+    MaxBackers(u64),
+    SoftCap(u64),
+    HardCap(u64),
+    Oversubscription(bool),
+    MaxBackedProposals(u64),
+    RefundFees(RefundFeeSchedule),
+    AllocationMode(AllocationMode),
+    MinBackingDuration(i64),
+    MaxBackingDuration(i64),
+    WithdrawalLockoutSeconds(i64),
+    SqrtPriceToleranceBps(u16),
+    ConfigUpdateDelay(i64),
+    Treasury(Pubkey),
+    PoolFeeSplit(PoolFeeSplit),
+    PoolFeeSchedule(PoolFeeSchedule),
+    PoolActivation(PoolActivation),
+    PoolPriceRange(PoolPriceRange),
+    ResidualPolicy(ResidualPolicy),
+    ResidualQuotePolicy(ResidualQuotePolicy),
+}
+
+impl ConfigParam {
+    /// Role allowed to queue, execute and cancel an update of this field.
+    pub fn role(&self) -> AdminRole {
+        match self {
+            ConfigParam::Treasury(_) | ConfigParam::PoolFeeSplit(_) => AdminRole::TreasuryManager,
+            _ => AdminRole::Configurator,
+        }
+    }
+}
+
+/// A config change waiting out `Configs::config_update_delay`.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingConfigUpdate {
+    pub update_id: u64,
+    pub param: ConfigParam,
+    pub queued_by: Pubkey,   // Receives the rent back when the update is executed or cancelled
+    pub queued_at: i64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl Configs {
+    /// Current value of the field `param` refers to.
+    pub fn current_value(&self, param: &ConfigParam) -> ConfigParam {
+        match param {
+            ConfigParam::MinAmountPerUser(_) => ConfigParam::MinAmountPerUser(self.min_amount_per_user),
+            ConfigParam::MaxAmountPerUser(_) => ConfigParam::MaxAmountPerUser(self.max_amount_per_user),
+            ConfigParam::TotalMint(_) => ConfigParam::TotalMint(self.total_mint),
+            ConfigParam::TotalPoolTokens(_) => ConfigParam::TotalPoolTokens(self.total_pool_tokens),
+            ConfigParam::MakerTokenAmount(_) => ConfigParam::MakerTokenAmount(self.maker_token_amount),
+            ConfigParam::TotalAirdropAmountPerMilestone(_) => {
+                ConfigParam::TotalAirdropAmountPerMilestone(self.total_airdrop_amount_per_milestone)
+            }
+            ConfigParam::MinBackers(_) => ConfigParam::MinBackers(self.min_backers),
+            ConfigParam::MaxBackers(_) => ConfigParam::MaxBackers(self.max_backers),
+            ConfigParam::SoftCap(_) => ConfigParam::SoftCap(self.soft_cap),
+            ConfigParam::HardCap(_) => ConfigParam::HardCap(self.hard_cap),
+            ConfigParam::Oversubscription(_) => ConfigParam::Oversubscription(self.oversubscription),
+            ConfigParam::MaxBackedProposals(_) => {
+                ConfigParam::MaxBackedProposals(self.max_backed_proposals)
+            }
+            ConfigParam::RefundFees(_) => ConfigParam::RefundFees(self.refund_fees),
+            ConfigParam::AllocationMode(_) => ConfigParam::AllocationMode(self.allocation_mode),
+            ConfigParam::MinBackingDuration(_) => {
+                ConfigParam::MinBackingDuration(self.min_backing_duration)
+            }
+            ConfigParam::MaxBackingDuration(_) => {
+                ConfigParam::MaxBackingDuration(self.max_backing_duration)
+            }
+            ConfigParam::WithdrawalLockoutSeconds(_) => {
+                ConfigParam::WithdrawalLockoutSeconds(self.withdrawal_lockout_seconds)
+            }
+            ConfigParam::SqrtPriceToleranceBps(_) => {
+                ConfigParam::SqrtPriceToleranceBps(self.sqrt_price_tolerance_bps)
+            }
+            ConfigParam::ConfigUpdateDelay(_) => {
+                ConfigParam::ConfigUpdateDelay(self.config_update_delay)
+            }
+            ConfigParam::Treasury(_) => ConfigParam::Treasury(self.treasury),
+            ConfigParam::PoolFeeSplit(_) => ConfigParam::PoolFeeSplit(self.pool_fee_split),
+            ConfigParam::PoolFeeSchedule(_) => ConfigParam::PoolFeeSchedule(self.pool_fee_schedule),
+            ConfigParam::PoolActivation(_) => ConfigParam::PoolActivation(self.pool_activation),
+            ConfigParam::PoolPriceRange(_) => ConfigParam::PoolPriceRange(self.pool_price_range),
+            ConfigParam::ResidualPolicy(_) => ConfigParam::ResidualPolicy(self.residual_policy),
+            ConfigParam::ResidualQuotePolicy(_) => {
+                ConfigParam::ResidualQuotePolicy(self.residual_quote_policy)
+            }
+        }
+    }
+
+    pub fn apply(&mut self, param: ConfigParam) {
+        match param {
+            ConfigParam::MinAmountPerUser(value) => self.min_amount_per_user = value,
+            ConfigParam::MaxAmountPerUser(value) => self.max_amount_per_user = value,
+            ConfigParam::TotalMint(value) => self.total_mint = value,
+            ConfigParam::TotalPoolTokens(value) => self.total_pool_tokens = value,
+            ConfigParam::MakerTokenAmount(value) => self.maker_token_amount = value,
+            ConfigParam::TotalAirdropAmountPerMilestone(value) => {
+                self.total_airdrop_amount_per_milestone = value
+            }
+            ConfigParam::MinBackers(value) => self.min_backers = value,
+            ConfigParam::MaxBackers(value) => self.max_backers = value,
+            ConfigParam::SoftCap(value) => self.soft_cap = value,
+            ConfigParam::HardCap(value) => self.hard_cap = value,
+            ConfigParam::Oversubscription(value) => self.oversubscription = value,
+            ConfigParam::MaxBackedProposals(value) => self.max_backed_proposals = value,
+            ConfigParam::RefundFees(value) => self.refund_fees = value,
+            ConfigParam::AllocationMode(value) => self.allocation_mode = value,
+            ConfigParam::MinBackingDuration(value) => self.min_backing_duration = value,
+            ConfigParam::MaxBackingDuration(value) => self.max_backing_duration = value,
+            ConfigParam::WithdrawalLockoutSeconds(value) => self.withdrawal_lockout_seconds = value,
+            ConfigParam::SqrtPriceToleranceBps(value) => self.sqrt_price_tolerance_bps = value,
+            ConfigParam::ConfigUpdateDelay(value) => self.config_update_delay = value,
+            ConfigParam::Treasury(value) => self.treasury = value,
+            ConfigParam::PoolFeeSplit(value) => self.pool_fee_split = value,
+            ConfigParam::PoolFeeSchedule(value) => self.pool_fee_schedule = value,
+            ConfigParam::PoolActivation(value) => self.pool_activation = value,
+            ConfigParam::PoolPriceRange(value) => self.pool_price_range = value,
+            ConfigParam::ResidualPolicy(value) => self.residual_policy = value,
+            ConfigParam::ResidualQuotePolicy(value) => self.residual_quote_policy = value,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::{AllocationMode, PoolFeeSplit, ProposalTerms};
+
+/// A named set of launch economics a maker can pick instead of the global config.
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchTemplate {
+    pub template_id: u64,
+    #[max_len(32)]
+    pub name: String,
+    pub terms: ProposalTerms,
+    pub allocation_mode: AllocationMode,
+    pub pool_fee_split: Option<PoolFeeSplit>, // Used when the maker does not pass an override
+    pub retired: bool,                        // Retired templates cannot back new proposals
+    pub bump: u8,
+}
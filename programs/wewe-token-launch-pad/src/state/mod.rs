@@ -3,3 +3,6 @@ pub mod maker;
 pub mod proposal;
 pub mod config;
 pub mod backer_proposal_count;
+pub mod admin_registry;
+pub mod config_update;
+pub mod launch_template;
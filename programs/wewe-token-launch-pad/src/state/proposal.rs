@@ -1,24 +1,59 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    errors::ProposalError,
+    state::config::{
+        AllocationMode, PoolFeeSchedule, PoolFeeSplit, PoolLaunchTerms, ProposalTerms,
+        ResidualPolicy, ResidualQuotePolicy,
+    },
+};
+
 #[account]
 #[derive(InitSpace)]
 pub struct Proposal {
     pub maker: Pubkey,
     pub mint_account: Pubkey,
     pub time_started: i64,
+    pub backing_ends_at: i64,
     pub bump: u8,
-    pub is_rejected: bool,
+    pub status: ProposalStatus,
+    pub rejection_cause: Option<RejectionCause>, // Set when the proposal becomes refundable
     pub proposal_id: u64,
-    pub is_pool_launched: bool,
     pub total_backers: u64,
     pub total_backing: u64,
     pub current_airdrop_cycle: u8,
-    pub milestone_active: bool,
     pub milestone_units_assigned: u64,
     pub milestone_backers_weighted: u64,
     pub milestone_reputation_sum: u64,
+    pub allocation_mode: AllocationMode,
+    pub milestone_share_sum: u64, // Sum of per-backer shares snapshotted in the current milestone
+    pub rounding_dust: u64,       // Launch airdrop dust not burned yet; swept at the next milestone end
+    pub airdrop_dust_remainder: u64, // Rounded-away airdrop shares short of a whole unit, over the allocation denominator
     pub launch_timestamp: Option<i64>,
     pub emergency_unlocked: bool,
+    pub rent_payer: Pubkey,    // Receives the rent back when the proposal is closed
+    pub closed_backers: u64,   // Backer accounts closed after completion
+    pub closed_backing: u64,   // Deposits of the closed backer accounts; they no longer earn pool fees
+    pub frozen: bool,          // Set by the pauser; halts user-facing instructions for this proposal
+    pub pool_fee_split: Option<PoolFeeSplit>, // Overrides `Configs::pool_fee_split` when set
+    pub backer_fee_pool_token: u64, // Backer share of claimed token fees held in the token vault
+    pub backer_fee_pool_wsol: u64,  // Backer share of claimed WSOL fees held in the backer fee vault
+    pub backer_fee_index_token: u128, // Token fees per backed lamport, scaled by FEE_INDEX_PRECISION
+    pub backer_fee_index_wsol: u128,  // WSOL fees per backed lamport, scaled by FEE_INDEX_PRECISION
+    pub pool_address: Option<Pubkey>, // DAMM v2 pool created at launch
+    pub pool_position: Option<Pubkey>, // Locked launch position whose fees claim_position_fee collects
+    pub pool_fee_schedule: Option<PoolFeeSchedule>, // Fee curve the pool was created with
+    pub pool_activation_point: Option<u64>, // Slot or timestamp trading opened; None if at creation
+    pub pool_sqrt_min_price: u128, // Lower bound of the launch position
+    pub pool_sqrt_max_price: u128, // Upper bound of the launch position
+    pub residual_base: u64,        // Base tokens the launch position did not take
+    pub residual_quote: u64,       // Lamports of WSOL the launch position did not take
+    pub residual_policy: Option<ResidualPolicy>, // How the base residual was handled at launch
+    pub residual_quote_policy: Option<ResidualQuotePolicy>, // How the quote residual was handled at launch
+    pub terms: ProposalTerms,      // Config economics at creation; used instead of the live config
+    pub launch_terms: PoolLaunchTerms, // Config pool launch settings at creation; used by create_pool
+    pub launch_template: Option<u64>, // Template the terms came from; None for the global config
+    pub excess_refunded: u64,      // Oversubscribed lamports already paid back through claim_excess
     #[max_len(32)]
     pub token_name: String,
     #[max_len(10)]
@@ -26,3 +61,124 @@ pub struct Proposal {
     #[max_len(200)]
     pub token_uri: String,
 }
+
+/// Lifecycle of a proposal. Every status change goes through `Proposal::transition`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ProposalStatus {
+    /// Backing window is open.
+    Backing,
+    /// Raise met its target and is ready to launch.
+    Succeeded,
+    /// Raise failed or was rejected; backers can refund.
+    Rejected,
+    /// Pool is live and no milestone is running.
+    Launched,
+    /// Pool is live and a milestone is running.
+    MilestoneActive,
+    /// All milestones are over.
+    Completed,
+    /// Maker withdrew the proposal.
+    Cancelled,
+}
+
+/// Why backers are getting their deposit back; selects the refund fee.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum RejectionCause {
+    /// Backer left while the backing window was open.
+    BackerExit,
+    /// Platform called `reject_proposal`.
+    PlatformRejected,
+    /// Raise missed its target when finalized.
+    FailedRaise,
+    /// Maker called `cancel_proposal`.
+    MakerCancelled,
+}
+
+impl ProposalStatus {
+    pub fn can_transition_to(self, next: ProposalStatus) -> bool {
+        use ProposalStatus::*;
+        matches!(
+            (self, next),
+            (Backing, Succeeded)
+                | (Backing, Rejected)
+                | (Backing, Cancelled)
+                | (Succeeded, Launched)
+                | (Succeeded, Rejected)
+                | (Succeeded, Cancelled)
+                | (Launched, MilestoneActive)
+                | (Launched, Completed)
+                | (Launched, Succeeded)
+                | (MilestoneActive, Launched)
+                | (MilestoneActive, Succeeded)
+        )
+    }
+}
+
+impl Proposal {
+    pub fn transition(&mut self, next: ProposalStatus) -> Result<()> {
+        require!(
+            self.status.can_transition_to(next),
+            ProposalError::IllegalStateTransition
+        );
+        self.status = next;
+        Ok(())
+    }
+
+    /// Moves to Rejected or Cancelled and records why, so refunds pick the right fee.
+    pub fn reject(&mut self, cause: RejectionCause) -> Result<()> {
+        let next = if cause == RejectionCause::MakerCancelled {
+            ProposalStatus::Cancelled
+        } else {
+            ProposalStatus::Rejected
+        };
+        self.transition(next)?;
+        self.rejection_cause = Some(cause);
+        Ok(())
+    }
+
+    /// True once the pool exists, including after the milestones are over.
+    pub fn is_launched(&self) -> bool {
+        matches!(
+            self.status,
+            ProposalStatus::Launched | ProposalStatus::MilestoneActive | ProposalStatus::Completed
+        )
+    }
+
+    /// Backing the launch uses: in oversubscription mode the raise is capped at its target.
+    pub fn effective_backing(&self) -> u64 {
+        if self.terms.oversubscription {
+            self.total_backing.min(self.terms.hard_cap)
+        } else {
+            self.total_backing
+        }
+    }
+
+    /// Deposits of the backer accounts still open; pool fees are shared out over these.
+    pub fn open_backing(&self) -> u64 {
+        self.total_backing.saturating_sub(self.closed_backing)
+    }
+
+    /// Backing still held in the escrow before launch, net of claimed excess.
+    pub fn escrowed_backing(&self) -> u64 {
+        self.total_backing.saturating_sub(self.excess_refunded)
+    }
+
+    /// Part of a deposit above the backer's pro-rata share of the target.
+    /// Rounds down so the excess paid out never eats into the launch amount.
+    pub fn excess_of(&self, deposit_amount: u64) -> Result<u64> {
+        if self.total_backing == 0 {
+            return Ok(0);
+        }
+        let excess_total = self.total_backing - self.effective_backing();
+        let excess = (deposit_amount as u128)
+            .checked_mul(excess_total as u128)
+            .and_then(|n| n.checked_div(self.total_backing as u128))
+            .ok_or(ProposalError::NumericalOverflow)?;
+        u64::try_from(excess).map_err(|_| ProposalError::TypeCastFailed.into())
+    }
+
+    /// The pool fee split for this proposal: its own override, or the config default.
+    pub fn effective_pool_fee_split(&self, default: PoolFeeSplit) -> PoolFeeSplit {
+        self.pool_fee_split.unwrap_or(default)
+    }
+}
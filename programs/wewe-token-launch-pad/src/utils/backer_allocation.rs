@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ProposalError, state::config::AllocationMode};
+
+/// Share of `pool_amount` owed to a single backer (round down).
+///
+/// - `Equal`: `pool_amount / total_backers`
+/// - `DepositWeighted`: `pool_amount * deposit_amount / total_backing`
+///
+/// The remainder left by rounding down is burned as rounding dust: a milestone's
+/// when the milestone ends, the launch airdrop's via `get_allocation_remainder`.
+pub fn get_backer_allocation(
+    mode: AllocationMode,
+    pool_amount: u64,
+    deposit_amount: u64,
+    total_backing: u64,
+    total_backers: u64,
+) -> Result<u64> {
+    let (numerator, denominator) = allocation_ratio(mode, deposit_amount, total_backing, total_backers);
+
+    let allocation = (pool_amount as u128)
+        .checked_mul(numerator as u128)
+        .and_then(|n| n.checked_div(denominator as u128))
+        .ok_or(ProposalError::NumericalOverflow)?;
+
+    Ok(allocation
+        .try_into()
+        .map_err(|_| ProposalError::TypeCastFailed)?)
+}
+
+/// Fraction of a unit `get_backer_allocation` rounded away, as `(remainder, denominator)`.
+/// Summed over every backer the remainders add up to `denominator * dust`.
+pub fn get_allocation_remainder(
+    mode: AllocationMode,
+    pool_amount: u64,
+    deposit_amount: u64,
+    total_backing: u64,
+    total_backers: u64,
+) -> Result<(u64, u64)> {
+    let (numerator, denominator) = allocation_ratio(mode, deposit_amount, total_backing, total_backers);
+
+    let remainder = (pool_amount as u128)
+        .checked_mul(numerator as u128)
+        .and_then(|n| n.checked_rem(denominator as u128))
+        .ok_or(ProposalError::NumericalOverflow)?;
+
+    // remainder < denominator, which is itself a u64
+    Ok((remainder as u64, denominator))
+}
+
+fn allocation_ratio(
+    mode: AllocationMode,
+    deposit_amount: u64,
+    total_backing: u64,
+    total_backers: u64,
+) -> (u64, u64) {
+    match mode {
+        AllocationMode::Equal => (1u64, total_backers),
+        AllocationMode::DepositWeighted => (deposit_amount, total_backing),
+    }
+}
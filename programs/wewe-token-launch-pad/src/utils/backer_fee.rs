@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProposalError;
+
+/// Scale of the reward-per-share indices on `Proposal`.
+pub const FEE_INDEX_PRECISION: u128 = 1_000_000_000_000;
+
+/// Advances a reward-per-share index by `amount` spread over `total_backing` lamports.
+pub fn accrue_fee_index(index: u128, amount: u64, total_backing: u64) -> Result<u128> {
+    let delta = (amount as u128)
+        .checked_mul(FEE_INDEX_PRECISION)
+        .and_then(|n| n.checked_div(total_backing as u128))
+        .ok_or(ProposalError::NumericalOverflow)?;
+
+    Ok(index
+        .checked_add(delta)
+        .ok_or(ProposalError::NumericalOverflow)?)
+}
+
+/// Fees owed to a backer since their last checkpoint (round down).
+pub fn pending_backer_fee(deposit_amount: u64, index: u128, checkpoint: u128) -> Result<u64> {
+    let pending = index
+        .checked_sub(checkpoint)
+        .and_then(|delta| delta.checked_mul(deposit_amount as u128))
+        .and_then(|n| n.checked_div(FEE_INDEX_PRECISION))
+        .ok_or(ProposalError::NumericalOverflow)?;
+
+    Ok(pending
+        .try_into()
+        .map_err(|_| ProposalError::TypeCastFailed)?)
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ProposalError,
+    state::{admin_registry::AdminRegistry, config_update::ConfigParam},
+};
+
+pub fn check_config_update_authority(
+    signer_account: &AccountInfo,
+    admin_registry: &AdminRegistry,
+    param: &ConfigParam,
+) -> Result<()> {
+    // Treasury fields stay with the treasury manager, everything else with the configurator
+    require_keys_eq!(
+        signer_account.key(),
+        admin_registry.holder(param.role()),
+        ProposalError::NotOwner
+    );
+
+    Ok(())
+}
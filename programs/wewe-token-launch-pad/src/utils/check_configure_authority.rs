@@ -1,15 +1,17 @@
 use anchor_lang::prelude::*;
 
-use crate::{constant::configure_authority_pubkey, errors::ProposalError};
+use crate::{errors::ProposalError, state::admin_registry::AdminRegistry};
 
-pub fn check_configure_authority(signer_account: &AccountInfo) -> Result<()> {
-    // Check if signer === configure_authority (for set_config operations)
+pub fn check_configure_authority(
+    signer_account: &AccountInfo,
+    admin_registry: &AdminRegistry,
+) -> Result<()> {
+    // Check if signer === configurator (for set_config operations)
     require_keys_eq!(
         signer_account.key(),
-        configure_authority_pubkey::ID,
+        admin_registry.configurator,
         ProposalError::NotOwner
     );
 
     Ok(())
 }
-
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ProposalError, state::admin_registry::AdminRegistry};
+
+pub fn check_pauser(signer_account: &AccountInfo, admin_registry: &AdminRegistry) -> Result<()> {
+    // Check if signer === pauser
+    require_keys_eq!(
+        signer_account.key(),
+        admin_registry.pauser,
+        ProposalError::NotOwner
+    );
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProposalError;
+
+/// Checks that a proposal escrow still covers the proposal's `total_backing`.
+///
+/// The escrow is funded with its rent-exempt minimum when the proposal is created,
+/// so that reserve is excluded. Lamports sent directly to the PDA are ignored rather
+/// than treated as a violation, so a donation cannot lock deposits or refunds.
+pub fn check_escrow_balance(escrow: &AccountInfo, total_backing: u64) -> Result<()> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let escrowed = escrow.lamports().saturating_sub(rent_exempt_minimum);
+
+    require!(
+        escrowed >= total_backing,
+        ProposalError::EscrowBalanceMismatch
+    );
+
+    Ok(())
+}
@@ -2,7 +2,17 @@ pub mod only_owner;
 pub mod pool_liqudity;
 pub mod pool_validation;
 pub mod check_configure_authority;
+pub mod backer_allocation;
+pub mod escrow;
+pub mod refund_fee;
+pub mod pause;
+pub mod check_pauser;
+pub mod check_config_update_authority;
+pub mod pool_fee;
+pub mod backer_fee;
 
 pub use only_owner::*;
 pub use pool_validation::*;
 pub use check_configure_authority::*;
+pub use check_pauser::*;
+pub use check_config_update_authority::*;
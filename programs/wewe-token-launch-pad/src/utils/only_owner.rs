@@ -1,12 +1,12 @@
 use anchor_lang::prelude::*;
 
-use crate::{constant::chain_service_pubkey, errors::ProposalError};
+use crate::{errors::ProposalError, state::admin_registry::AdminRegistry};
 
-pub fn check(signer_account: &AccountInfo) -> Result<()> {
-    // Check if signer === owner
+pub fn check(signer_account: &AccountInfo, admin_registry: &AdminRegistry) -> Result<()> {
+    // Check if signer === operator
     require_keys_eq!(
         signer_account.key(),
-        chain_service_pubkey::ID,
+        admin_registry.operator,
         ProposalError::NotOwner
     );
 
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ProposalError,
+    state::{config::Configs, proposal::Proposal},
+};
+
+/// Fails when the pauser halted the whole program or froze this proposal.
+pub fn check_not_paused(config: &Configs, proposal: &Proposal) -> Result<()> {
+    require!(!config.paused, ProposalError::ProgramPaused);
+    require!(!proposal.frozen, ProposalError::ProposalFrozen);
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use damm_v2_cpi::{
+    params::fee_parameters::{BaseFeeParameters, DynamicFeeParameters, PoolFeeParameters},
+    state::fee::BaseFeeMode,
+};
+
+use crate::{
+    errors::ProposalError,
+    state::config::{FeeSchedulerMode, PoolFeeSchedule, PoolFeeSplit},
+};
+
+const BASIS_POINTS: u128 = 10_000;
+
+/// Splits a claimed fee amount into `(maker, treasury, backer)` shares.
+///
+/// The maker and backer shares round down; the treasury gets the remainder,
+/// so the three shares always add up to `amount`.
+pub fn split_pool_fee(amount: u64, split: &PoolFeeSplit) -> Result<(u64, u64, u64)> {
+    let share = |bps: u16| -> Result<u64> {
+        (amount as u128)
+            .checked_mul(bps as u128)
+            .and_then(|n| n.checked_div(BASIS_POINTS))
+            .ok_or(ProposalError::NumericalOverflow)?
+            .try_into()
+            .map_err(|_| error!(ProposalError::NumericalOverflow))
+    };
+
+    let maker = share(split.maker_bps)?;
+    let backer = share(split.backer_bps)?;
+    let treasury = amount
+        .checked_sub(maker)
+        .and_then(|n| n.checked_sub(backer))
+        .ok_or(ProposalError::NumericalOverflow)?;
+
+    Ok((maker, treasury, backer))
+}
+
+/// DAMM v2 fee parameters for a configured fee schedule.
+pub fn pool_fee_parameters(schedule: &PoolFeeSchedule) -> PoolFeeParameters {
+    let base_fee_mode = match schedule.scheduler_mode {
+        FeeSchedulerMode::Linear => BaseFeeMode::FeeSchedulerLinear,
+        FeeSchedulerMode::Exponential => BaseFeeMode::FeeSchedulerExponential,
+    };
+
+    PoolFeeParameters {
+        base_fee: BaseFeeParameters {
+            cliff_fee_numerator: schedule.cliff_fee_numerator,
+            base_fee_mode: base_fee_mode as u8,
+            first_factor: schedule.number_of_periods,
+            second_factor: schedule.period_frequency.to_le_bytes(),
+            third_factor: schedule.reduction_factor,
+        },
+        dynamic_fee: schedule.dynamic_fee.map(|dynamic| DynamicFeeParameters {
+            bin_step: dynamic.bin_step,
+            bin_step_u128: dynamic.bin_step_u128,
+            filter_period: dynamic.filter_period,
+            decay_period: dynamic.decay_period,
+            reduction_factor: dynamic.reduction_factor,
+            max_volatility_accumulator: dynamic.max_volatility_accumulator,
+            variable_fee_control: dynamic.variable_fee_control,
+        }),
+        ..Default::default()
+    }
+}
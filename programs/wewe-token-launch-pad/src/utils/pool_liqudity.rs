@@ -38,21 +38,12 @@ pub fn get_liquidity_delta(
     min_sqrt_price: u128,
     max_sqrt_price: u128,
 ) -> Result<u128> {
-    msg!("base_amount: {}", base_amount);
-    msg!("quote_amount: {}", quote_amount);
-    msg!("sqrt_price: {}", sqrt_price);
-    msg!("min_sqrt_price: {}", min_sqrt_price);
-    msg!("max_sqrt_price: {}", max_sqrt_price);
-
     let liquidity_from_base =
         get_initial_liquidity_from_delta_base(base_amount, sqrt_price, max_sqrt_price)?;
 
     let liquidity_from_quote =
         get_initial_liquidity_from_delta_quote(quote_amount, min_sqrt_price, sqrt_price)?;
 
-    msg!("liquidity_from_base: {}", liquidity_from_base);
-    msg!("liquidity_from_quote: {}", liquidity_from_quote);
-
     Ok(std::cmp::min(
         liquidity_from_base
             .try_into()
@@ -62,3 +53,52 @@ pub fn get_liquidity_delta(
             .map_err(|_| PoolError::TypeCastFailed)?,
     ))
 }
+
+// √P = √(Δb / Δa) in Q64.64 => √P = isqrt(Δb * 2^128 / Δa) (round down)
+pub fn get_sqrt_price_from_amounts(base_amount: u64, quote_amount: u64) -> Result<u128> {
+    let ratio = U256::from(quote_amount)
+        .safe_shl(128)?
+        .safe_div(U256::from(base_amount))?;
+
+    Ok(isqrt(ratio)?
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?)
+}
+
+// √(P * k) = isqrt(√P² * k_bps / 10000), clamped to DAMM's price bounds
+pub fn get_sqrt_price_bound(
+    sqrt_price: u128,
+    price_bps: u32,
+    min_sqrt_price: u128,
+    max_sqrt_price: u128,
+) -> Result<u128> {
+    let scaled = U256::from(sqrt_price)
+        .safe_mul(U256::from(sqrt_price))?
+        .safe_mul(U256::from(price_bps))?
+        .safe_div(U256::from(10_000u16))?;
+    let bound = U256::from(min_sqrt_price).max(isqrt(scaled)?.min(U256::from(max_sqrt_price)));
+
+    Ok(bound
+        .try_into()
+        .map_err(|_| PoolError::TypeCastFailed)?)
+}
+
+// Integer square root by Newton's method (round down)
+fn isqrt(value: U256) -> Result<U256> {
+    let mut root = value;
+    let mut next = value.safe_add(U256::from(1u8))?.safe_shr(1)?;
+    while next < root {
+        root = next;
+        next = value.safe_div(next)?.safe_add(next)?.safe_shr(1)?;
+    }
+    Ok(root)
+}
+
+/// True when `sqrt_price` is within `tolerance_bps` of `expected`.
+pub fn is_sqrt_price_within_tolerance(sqrt_price: u128, expected: u128, tolerance_bps: u16) -> Result<bool> {
+    let max_deviation = U256::from(expected)
+        .safe_mul(U256::from(tolerance_bps))?
+        .safe_div(U256::from(10_000u16))?;
+    let deviation = U256::from(sqrt_price.abs_diff(expected));
+    Ok(deviation <= max_deviation)
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ProposalError;
+
+const BASIS_POINTS: u128 = 10_000;
+
+/// Splits a deposit into `(refund_amount, fee)` where `fee = deposit * fee_bps / 10000`
+/// (round down), so `refund_amount + fee == deposit` always holds.
+pub fn split_refund(deposited_amount: u64, fee_bps: u16) -> Result<(u64, u64)> {
+    let fee: u64 = (deposited_amount as u128)
+        .checked_mul(fee_bps as u128)
+        .and_then(|n| n.checked_div(BASIS_POINTS))
+        .ok_or(ProposalError::NumericalOverflow)?
+        .try_into()
+        .map_err(|_| ProposalError::NumericalOverflow)?;
+
+    let refund_amount = deposited_amount
+        .checked_sub(fee)
+        .ok_or(ProposalError::NumericalOverflow)?;
+
+    Ok((refund_amount, fee))
+}
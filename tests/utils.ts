@@ -39,6 +39,18 @@ export const findProposalPDA = (programId: anchor.web3.PublicKey, maker: anchor.
     index.toArrayLike(Buffer, "le", 8),
   ], programId)[0];
 
+export const findProposalEscrowPDA = (programId: anchor.web3.PublicKey, proposal: anchor.web3.PublicKey) =>
+  anchor.web3.PublicKey.findProgramAddressSync([
+    Buffer.from('proposal_escrow'),
+    proposal.toBuffer(),
+  ], programId)[0];
+
+export const findBackerFeeVaultPDA = (programId: anchor.web3.PublicKey, proposal: anchor.web3.PublicKey) =>
+  anchor.web3.PublicKey.findProgramAddressSync([
+    Buffer.from('backer_fee_vault'),
+    proposal.toBuffer(),
+  ], programId)[0];
+
 export const findBackerAccountPDA = (programId: anchor.web3.PublicKey, proposal: anchor.web3.PublicKey, backer: anchor.web3.PublicKey) =>
   anchor.web3.PublicKey.findProgramAddressSync([
     Buffer.from('backer'),
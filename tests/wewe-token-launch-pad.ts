@@ -10,6 +10,7 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddressSync,
   createAssociatedTokenAccountInstruction,
+  createAssociatedTokenAccountIdempotentInstruction,
   createTransferInstruction,
   createSyncNativeInstruction,
   getMint,
@@ -38,6 +39,8 @@ import {
   findBackerProposalCountPDA,
   findMetadataPDA,
   findTempWsolPDA,
+  findProposalEscrowPDA,
+  findBackerFeeVaultPDA,
 } from './utils';
 
 const TOKEN_METADATA_PROGRAM_ID = new anchor.web3.PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
@@ -93,6 +96,25 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     program.programId
   );
   const weweTreasury = new anchor.web3.PublicKey("76U9hvHNUNn7YV5FekSzDHzqnHETsUpDKq4cMj2dMxNi");
+
+  // Per-user backing bounds set in test 1 and the amount each test backer deposits
+  const MIN_AMOUNT_PER_USER = new BN(100_000_000); // 0.1 SOL
+  const MAX_AMOUNT_PER_USER = new BN(5_000_000_000); // 5 SOL
+  const BACKING_AMOUNT = new BN(1_000_000_000); // 1 SOL
+  const MIN_BACKING_DURATION = new BN(10); // 10 seconds, so launched proposals can close their window in tests
+  const MAX_BACKING_DURATION = new BN(7 * 86_400); // 7 days
+  const BACKING_DURATION = new BN(3 * 86_400); // 3 days
+  const LAUNCH_BACKING_DURATION = new BN(20); // Proposals that reach create_pool
+  const WITHDRAWAL_LOCKOUT_SECONDS = new BN(3_600); // 1 hour
+  const SQRT_PRICE_TOLERANCE_BPS = 50; // 0.5%
+  const CONFIG_UPDATE_DELAY = new BN(0); // Queued config updates can run right away in tests
+  // Refund fee per rejection cause; only backers who exit on their own pay a fee
+  const REFUND_FEES = {
+    backerExitBps: 200, // 2%
+    platformRejectedBps: 0,
+    failedRaiseBps: 0,
+    makerCancelledBps: 0,
+  };
   
   const mint = anchor.web3.Keypair.generate();
   const mint2 = anchor.web3.Keypair.generate();
@@ -115,7 +137,114 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
   // Use hardcoded config address from devnet
   const config = new anchor.web3.PublicKey("7xeqWqnS4VMguYMexgtw1vxXt3cM8eh6vyvUkYgv2aJY");
+
+  // sqrt_price create_pool expects: total_backing / (terms.total_pool_tokens * 10^9), in Q64.64
+  const getLaunchSqrtPrice = async (proposalKey: anchor.web3.PublicKey): Promise<BN> => {
+    const proposalData = await program.account.proposal.fetch(proposalKey);
+    const baseAmount = proposalData.terms.totalPoolTokens.mul(new BN(10).pow(new BN(9)));
+    const price = new Decimal(proposalData.totalBacking.toString())
+      .div(new Decimal(baseAmount.toString()))
+      .toString();
+    return getSqrtPriceFromPrice(price, 9, 9);
+  };
+  // create_pool needs a Succeeded proposal, so wait out the backing window and finalize it
+  const waitForBackingEnd = async (proposalKey: anchor.web3.PublicKey) => {
+    const proposalData = await program.account.proposal.fetch(proposalKey);
+    while (((await provider.connection.getBlockTime(await provider.connection.getSlot())) ?? 0) <= proposalData.backingEndsAt.toNumber()) {
+      await new Promise((resolve) => setTimeout(resolve, 1_000));
+    }
+  };
+  const finalizeAfterBacking = async (proposalKey: anchor.web3.PublicKey) => {
+    await waitForBackingEnd(proposalKey);
+    await program.methods
+      .finalizeProposal()
+      .accountsPartial({
+        payer: provider.wallet.publicKey,
+        proposal: proposalKey,
+      })
+      .rpc()
+      .then(confirm);
+  };
+  // Queue a config change and, once its timelock has passed, apply it
+  const findConfigUpdatePDA = (updateId: BN) =>
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('config_update'), updateId.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0];
+  const queueConfigUpdate = async (param: any): Promise<anchor.web3.PublicKey> => {
+    const configData = await program.account.configs.fetch(configStruct);
+    const pendingUpdate = findConfigUpdatePDA(configData.nextConfigUpdateId);
+    await program.methods
+      .queueConfigUpdate(param)
+      .accountsPartial({
+        authority: configureAuthority.publicKey,
+        pendingUpdate,
+      })
+      .signers([configureAuthority])
+      .rpc()
+      .then(confirm);
+    return pendingUpdate;
+  };
+  const executeConfigUpdate = async (pendingUpdate: anchor.web3.PublicKey) =>
+    program.methods
+      .executeConfigUpdate()
+      .accountsPartial({
+        authority: configureAuthority.publicKey,
+        pendingUpdate,
+        queuedBy: configureAuthority.publicKey,
+      })
+      .signers([configureAuthority])
+      .rpc()
+      .then(confirm);
   const pdas = derivePoolPDAs(program.programId, cpAmm.programId, mint.publicKey, WSOL_MINT, maker.publicKey, config);
+  // claim_pool_fee only takes the pool and position recorded on the proposal at launch
+  const getClaimPoolFeeAccounts = async (proposalKey: anchor.web3.PublicKey, payer: anchor.web3.PublicKey) => {
+    const proposalData = await program.account.proposal.fetch(proposalKey);
+    const configData = await program.account.configs.fetch(configStruct);
+    const poolData = await cpAmm.account.pool.fetch(proposalData.poolAddress);
+    const positionData = await cpAmm.account.position.fetch(proposalData.poolPosition);
+    const [positionNftAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("position_nft_account"), positionData.nftMint.toBuffer()],
+      cpAmm.programId
+    );
+    const [tokenAAccount] = getTokenVaultAddress(vaultAuthority, proposalData.mintAccount, program.programId);
+    const [tokenBAccount] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
+    // The backer fee vault is left out once every backer account is closed
+    const backerFeeVault = proposalData.totalBacking.gt(proposalData.closedBacking)
+      ? findBackerFeeVaultPDA(program.programId, proposalKey)
+      : null;
+    return {
+      poolAuthority: pdas.poolAuthority,
+      payer,
+      maker: proposalData.maker,
+      weweTreasury: configData.treasury,
+      proposal: proposalKey,
+      vaultAuthority,
+      weweWsolAccount: getAssociatedTokenAddressSync(WSOL_MINT, configData.treasury, true),
+      weweTokenAccount: getAssociatedTokenAddressSync(proposalData.mintAccount, configData.treasury, true),
+      makerWsolAccount: getAssociatedTokenAddressSync(WSOL_MINT, proposalData.maker, true),
+      makerTokenAccount: getAssociatedTokenAddressSync(proposalData.mintAccount, proposalData.maker, true),
+      pool: proposalData.poolAddress,
+      position: proposalData.poolPosition,
+      tokenAAccount,
+      tokenBAccount,
+      backerFeeVault,
+      tokenAVault: poolData.tokenAVault,
+      tokenBVault: poolData.tokenBVault,
+      tokenAMint: proposalData.mintAccount,
+      tokenBMint: WSOL_MINT,
+      positionNftAccount,
+      treasuryTempWsol: findTempWsolPDA(vaultAuthority, proposalKey, true),
+      makerTempWsol: findTempWsolPDA(vaultAuthority, proposalKey, false),
+      tokenAProgram: TOKEN_PROGRAM_ID,
+      tokenBProgram: TOKEN_PROGRAM_ID,
+      ammProgram: cpAmm.programId,
+      eventAuthority: pdas.dammEventAuthority,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    };
+  };
 
   // Setup: Ensure CP-AMM config account exists in local validator
   before(async () => {
@@ -423,25 +552,55 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     await Promise.all(airdropPromises.map(p => confirm(p)));
   });
 
+  it('0.5. Initialises the admin registry', async () => {
+    await program.methods
+      .initAdminRegistry(
+        authority.publicKey, // operator (chain service)
+        configureAuthority.publicKey, // pauser
+        configureAuthority.publicKey, // treasury_manager
+      )
+      .accounts({
+        authority: configureAuthority.publicKey,
+      })
+      .signers([configureAuthority])
+      .rpc()
+      .then(confirm);
+
+    const [adminRegistry] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('admin_registry')],
+      program.programId
+    );
+    const registry = await program.account.adminRegistry.fetch(adminRegistry);
+    expect(registry.configurator.toBase58()).to.equal(configureAuthority.publicKey.toBase58());
+    expect(registry.operator.toBase58()).to.equal(authority.publicKey.toBase58());
+  });
+
   it('1. Sets constant values', async () => {
-    const amountToRaisePerUser = new BN(1_000_000_000); // 1 SOL
+    const minAmountPerUser = MIN_AMOUNT_PER_USER;
+    const maxAmountPerUser = MAX_AMOUNT_PER_USER;
     const totalMint = new BN(1_000_000_000);
     const totalPoolTokens = new BN(150_000_000);
     const makerTokenAmount = new BN(10_000_000);
     const totalAirdropAmountPerMilestone = new BN(140_000_000);
     const minBackers = new BN(1);
     const maxBackedProposals = new BN(3);
-    const refundFeeBps = new BN(200); // 200 BPS = 2%
     const tx = await program.methods
       .setConfig(
-        amountToRaisePerUser,
+        minAmountPerUser,
+        maxAmountPerUser,
         totalMint,
         totalPoolTokens,
         makerTokenAmount,
         totalAirdropAmountPerMilestone,
         minBackers,
         maxBackedProposals,
-        refundFeeBps, // refund_fee_basis_points: 200 BPS = 2%
+        REFUND_FEES, // refund_fees
+        { depositWeighted: {} }, // allocation_mode
+        MIN_BACKING_DURATION,
+        MAX_BACKING_DURATION,
+        WITHDRAWAL_LOCKOUT_SECONDS,
+        SQRT_PRICE_TOLERANCE_BPS,
+        CONFIG_UPDATE_DELAY,
       )
       .accounts({
         authority: configureAuthority.publicKey,
@@ -455,26 +614,33 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
   });
 
   it('1.5. Fails when old authority (not configure_authority) tries to set config', async () => {
-    const amountToRaisePerUser = new BN(1_000_000_000); // 1 SOL
+    const minAmountPerUser = MIN_AMOUNT_PER_USER;
+    const maxAmountPerUser = MAX_AMOUNT_PER_USER;
     const totalMint = new BN(1_000_000_000);
     const totalPoolTokens = new BN(150_000_000);
     const makerTokenAmount = new BN(10_000_000);
     const totalAirdropAmountPerMilestone = new BN(140_000_000);
     const minBackers = new BN(1);
     const maxBackedProposals = new BN(3);
-    const refundFeeBps = new BN(200); // 200 BPS = 2%
   
     try {
       await program.methods
         .setConfig(
-          amountToRaisePerUser,
+          minAmountPerUser,
+          maxAmountPerUser,
           totalMint,
           totalPoolTokens,
           makerTokenAmount,
           totalAirdropAmountPerMilestone,
           minBackers,
           maxBackedProposals,
-          refundFeeBps,
+          REFUND_FEES, // refund_fees
+          { depositWeighted: {} }, // allocation_mode
+          MIN_BACKING_DURATION,
+          MAX_BACKING_DURATION,
+          WITHDRAWAL_LOCKOUT_SECONDS,
+          SQRT_PRICE_TOLERANCE_BPS,
+          CONFIG_UPDATE_DELAY,
         )
         .accounts({
           authority: authority.publicKey, // Using old authority, not configureAuthority
@@ -548,7 +714,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     const eventPromise = waitForEvent(program, 'proposalCreated');
 
     await program.methods
-      .createProposal(metadata.name, metadata.symbol, metadata.uri)
+      .createProposal(metadata.name, metadata.symbol, metadata.uri, LAUNCH_BACKING_DURATION, null)
       .accountsPartial({
         payer: authority.publicKey,
         maker: maker.publicKey,
@@ -573,13 +739,19 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
     expect(capturedEvent.maker.toBase58()).to.equal(expectedEvent.maker);
     expect(capturedEvent.proposalAddress.toBase58()).to.equal(expectedEvent.proposalAddress);
+
+    const proposalData = await program.account.proposal.fetch(proposal);
+    expect(proposalData.backingEndsAt.toString()).to.equal(
+      proposalData.timeStarted.add(LAUNCH_BACKING_DURATION).toString()
+    );
+    expect(capturedEvent.backingEndsAt.toString()).to.equal(proposalData.backingEndsAt.toString());
   });
 
   it('4. Creates second proposal with same maker', async () => {
     const eventPromise = waitForEvent(program, 'proposalCreated');
 
     await program.methods
-      .createProposal(metadata.name, metadata.symbol, metadata.uri)
+      .createProposal(metadata.name, metadata.symbol, metadata.uri, BACKING_DURATION, null)
       .accountsPartial({
         payer: authority.publicKey,
         maker: maker.publicKey,
@@ -618,7 +790,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     }
 
     await program.methods
-      .depositSol()
+      .depositSol(BACKING_AMOUNT)
       .accountsPartial({
         backer: backer.publicKey,
         weweVault: weweTreasury,
@@ -641,13 +813,64 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     expect(countAccount.backer.toBase58()).to.equal(backer.publicKey.toBase58());
   });
 
+  it('5.5. Fails when backing amount is outside the per-user bounds', async () => {
+    const backerProposalCount = findBackerProposalCountPDA(program.programId, backer.publicKey);
+
+    for (const [amount, expectedError] of [
+      [MIN_AMOUNT_PER_USER.subn(1), 'BackingAmountTooSmall'],
+      [MAX_AMOUNT_PER_USER.addn(1), 'BackingAmountTooBig'],
+    ] as [BN, string][]) {
+      try {
+        await program.methods
+          .depositSol(amount)
+          .accountsPartial({
+            backer: backer.publicKey,
+            mint: mintAccount,
+            userTokenAccount: userAta,
+            proposal: proposal2,
+            backerAccount: backerAccount2,
+            backerProposalCount,
+            vaultAuthority,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            config: configStruct
+          })
+          .signers([backer])
+          .rpc();
+
+        assert.fail(`Should not allow backing with ${amount.toString()} lamports`);
+      } catch (err) {
+        expect(err.message).to.include(expectedError);
+      }
+    }
+  });
+
+  it('5.6. Fails to finalize a proposal while its backing window is open', async () => {
+    try {
+      await program.methods
+        .finalizeProposal()
+        .accounts({
+          payer: provider.wallet.publicKey,
+          proposal: proposal2,
+          config: configStruct,
+        })
+        .rpc();
+
+      assert.fail('Should not allow finalizing before backing_ends_at');
+    } catch (err) {
+      expect(err.message).to.include('BackingNotEnded');
+    }
+
+    const proposalData = await program.account.proposal.fetch(proposal2);
+    expect(proposalData.status).to.deep.equal({ backing: {} });
+  });
+
   // Refactored test case to fix the failure
   it('6. Fails when user backs same proposal twice', async () => {
     const backerProposalCount = findBackerProposalCountPDA(program.programId, backer.publicKey);
     
     try {
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: backer.publicKey,
         weweVault: weweTreasury,
@@ -677,7 +900,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     expect(countAccountBefore.activeCount.toNumber()).to.equal(1);
 
     await program.methods
-      .depositSol()
+      .depositSol(BACKING_AMOUNT)
       .accountsPartial({
         backer: backer.publicKey,
         weweVault: weweTreasury,
@@ -702,7 +925,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     const eventPromise = waitForEvent(program, 'proposalCreated');
 
     await program.methods
-      .createProposal(metadata.name, metadata.symbol, metadata.uri)
+      .createProposal(metadata.name, metadata.symbol, metadata.uri, BACKING_DURATION, null)
       .accountsPartial({
         payer: authority.publicKey,
         maker: maker.publicKey,
@@ -728,7 +951,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     expect(countAccountBefore.activeCount.toNumber()).to.equal(2);
 
     await program.methods
-      .depositSol()
+      .depositSol(BACKING_AMOUNT)
       .accountsPartial({
         backer: backer.publicKey,
         weweVault: weweTreasury,
@@ -755,7 +978,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     const eventPromise = waitForEvent(program, 'proposalCreated');
 
     await program.methods
-      .createProposal(metadata.name, metadata.symbol, metadata.uri)
+      .createProposal(metadata.name, metadata.symbol, metadata.uri, LAUNCH_BACKING_DURATION, null)
       .accountsPartial({
         payer: authority.publicKey,
         maker: maker.publicKey,
@@ -782,7 +1005,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
     try {
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: backer.publicKey,
         weweVault: weweTreasury,
@@ -830,21 +1053,19 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
     const weweTreasury = new anchor.web3.PublicKey("76U9hvHNUNn7YV5FekSzDHzqnHETsUpDKq4cMj2dMxNi");
     
-    // Get refund fee basis points from config (should be 200 = 2%)
+    // Proposal 2 was rejected by the platform, so the platform-rejection fee applies (0 in tests)
     const configAccount = await program.account.configs.fetch(configStruct);
-    const refundFeeBps = configAccount.refundFeeBasisPoints;
-    
-    // Verify config is set to 200 bps (2%) as expected
-    expect(refundFeeBps).to.equal(200); // 200 basis points = 2%
+    const refundFeeBps = configAccount.refundFees.platformRejectedBps;
+    expect(refundFeeBps).to.equal(REFUND_FEES.platformRejectedBps);
     
-    const amountToRaisePerUser = new BN(1_000_000_000); // 1 SOL
-    const depositedAmount = amountToRaisePerUser; // Full amount is in vault (no upfront fee)
+    const backerAccountData = await program.account.backers.fetch(backerAccount2);
+    const depositedAmount = backerAccountData.depositAmount; // Full deposit is in vault (no upfront fee)
     
     // Calculate expected amounts based on config value (not hardcoded)
     // Fee is calculated as a percentage of the deposited amount
     // Formula: fee = deposited_amount * refundFeeBps / 10000
     //          refund_amount = deposited_amount - fee
-    // With refundFeeBps = 200 (2%): fee = 0.02 SOL, refund = 0.98 SOL
+    // With the platform-rejection fee at 0 bps the backer gets the full deposit back
     const BASIS_POINTS = new BN(10000);
     const expectedFee = depositedAmount.muln(refundFeeBps).div(BASIS_POINTS);
     const expectedRefund = depositedAmount.sub(expectedFee);
@@ -858,7 +1079,8 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     // Get balances before refund
     const backerBalanceBefore = await provider.connection.getBalance(backer.publicKey);
     const treasuryBalanceBefore = await provider.connection.getBalance(weweTreasury);
-    const vaultBalanceBeforeRefund = await provider.connection.getBalance(vaultAuthority);
+    const proposal2Escrow = findProposalEscrowPDA(program.programId, proposal2);
+    const vaultBalanceBeforeRefund = await provider.connection.getBalance(proposal2Escrow);
     
     console.log(`\n=== Balances BEFORE Refund ===`);
     console.log(`   Vault balance: ${vaultBalanceBeforeRefund} lamports (${vaultBalanceBeforeRefund / 1e9} SOL)`);
@@ -914,6 +1136,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     const event = await eventPromise;
     expect(event.refundAmount.toString()).to.equal(expectedRefund.toString());
     expect(event.weweFee.toString()).to.equal(expectedFee.toString());
+    expect(event.cause).to.deep.equal({ platformRejected: {} });
     
     // Enforce fee percentage: fee should be refundFeeBps basis points of deposited amount
     const actualRefundAmount = new BN(event.refundAmount.toString());
@@ -928,7 +1151,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     // Verify balances after refund
     const backerBalanceAfter = await provider.connection.getBalance(backer.publicKey);
     const treasuryBalanceAfter = await provider.connection.getBalance(weweTreasury);
-    const vaultBalanceAfter = await provider.connection.getBalance(vaultAuthority);
+    const vaultBalanceAfter = await provider.connection.getBalance(proposal2Escrow);
     
     console.log(`\n=== Balances AFTER Refund ===`);
     console.log(`   Vault balance: ${vaultBalanceAfter} lamports (${vaultBalanceAfter / 1e9} SOL)`);
@@ -993,6 +1216,76 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     console.log(`\n✓ Refund test completed successfully!\n`);
   });
 
+  it('9.5. Finalizes a proposal that met its targets as succeeded', async () => {
+    await waitForBackingEnd(proposal);
+    const proposalBefore = await program.account.proposal.fetch(proposal);
+    const eventPromise = waitForEvent(program, 'proposalFinalized');
+
+    await program.methods
+      .finalizeProposal()
+      .accountsPartial({
+        payer: provider.wallet.publicKey,
+        proposal,
+      })
+      .rpc()
+      .then(confirm);
+
+    const event = await eventPromise;
+    expect(event.proposalAddress.toBase58()).to.equal(proposal.toBase58());
+    expect(event.maker.toBase58()).to.equal(maker.publicKey.toBase58());
+    expect(event.mintAccount.toBase58()).to.equal(mint.publicKey.toBase58());
+    expect(event.succeeded).to.equal(true);
+    expect(event.totalBackers.toString()).to.equal(proposalBefore.totalBackers.toString());
+    expect(event.totalBacking.toString()).to.equal(proposalBefore.totalBacking.toString());
+    // Oversubscription is off, so the whole raise goes into the launch
+    expect(event.effectiveBacking.toString()).to.equal(proposalBefore.totalBacking.toString());
+    expect(event.finalizedBy.toBase58()).to.equal(provider.wallet.publicKey.toBase58());
+
+    const proposalAfter = await program.account.proposal.fetch(proposal);
+    expect(proposalAfter.status).to.deep.equal({ succeeded: {} });
+    expect(proposalAfter.rejectionCause).to.be.null;
+  });
+
+  it('9.6. Finalizes a proposal that missed its targets as a failed raise', async () => {
+    // proposal4 never got a backer past the max backed proposals limit
+    await waitForBackingEnd(proposal4);
+    const eventPromise = waitForEvent(program, 'proposalFinalized');
+
+    await program.methods
+      .finalizeProposal()
+      .accountsPartial({
+        payer: provider.wallet.publicKey,
+        proposal: proposal4,
+      })
+      .rpc()
+      .then(confirm);
+
+    const event = await eventPromise;
+    expect(event.proposalAddress.toBase58()).to.equal(proposal4.toBase58());
+    expect(event.succeeded).to.equal(false);
+    expect(event.totalBackers.toNumber()).to.equal(0);
+    expect(event.totalBacking.toNumber()).to.equal(0);
+
+    const proposalAfter = await program.account.proposal.fetch(proposal4);
+    expect(proposalAfter.status).to.deep.equal({ rejected: {} });
+    expect(proposalAfter.rejectionCause).to.deep.equal({ failedRaise: {} });
+
+    // A finalized proposal cannot be finalized again
+    try {
+      await program.methods
+        .finalizeProposal()
+        .accountsPartial({
+          payer: provider.wallet.publicKey,
+          proposal: proposal4,
+        })
+        .rpc();
+
+      assert.fail('Should not allow finalizing a proposal twice');
+    } catch (err) {
+      expect(err.message).to.include('ProposalAlreadyFinalized');
+    }
+  });
+
   it('10. Launches coin and creates DAMM pool', async () => {
     const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
 
@@ -1079,38 +1372,55 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
     // Create pool using createPool instruction (similar to SDK's createCustomPool)
     // The Rust program handles liquidity calculation internally
+    const createPoolAccounts = {
+      proposal,
+      vaultAuthority,
+      maker: maker.publicKey, // Maker is the proposal creator, not chain service
+      tokenVault: vault,
+      wsolVault,
+      poolAuthority: pdas.poolAuthority, // Must be DAMM v2 pool authority PDA (const_pda::const_authority::POOL_ID)
+      dammPoolAuthority: pdas.poolAuthority,
+      poolConfig: EXPECTED_CONFIG_PUBKEY,
+      pool: pdas.pool,
+      positionNftMint: pdas.positionNftMint.publicKey,
+      positionNftAccount: pdas.positionNftAccount,
+      position: pdas.position,
+      ammProgram: cpAmm.programId,
+      baseMint: mint.publicKey,
+      mintAccount: proposalData.mintAccount,
+      makerTokenAccount: pdas.makerTokenAccount,
+      quoteMint: WSOL_MINT,
+      tokenAVault: pdas.tokenAVault,
+      tokenBVault: pdas.tokenBVault,
+      payer: chainServiceAuthority.publicKey,
+      chainServicePubkey: chainServiceAuthority.publicKey,
+      tokenBaseProgram: TOKEN_PROGRAM_ID,
+      tokenQuoteProgram: TOKEN_PROGRAM_ID,
+      token2022Program: TOKEN_2022_PROGRAM_ID,
+      dammEventAuthority: pdas.dammEventAuthority,
+      weweTreasury,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      config: configStruct,
+    };
+
+    // A price away from the one the raise implies is rejected
+    try {
+      await program.methods
+        .createPool(initSqrtPrice.muln(2), null)
+        .accountsPartial(createPoolAccounts)
+        .preInstructions([computeUnitsIx])
+        .signers([chainServiceAuthority, pdas.positionNftMint])
+        .rpc();
+
+      assert.fail('Should not allow a sqrt_price outside the tolerance');
+    } catch (err) {
+      expect(err.message).to.include('SqrtPriceOutOfTolerance');
+    }
+
     const tx = await program.methods
-      .createPool(initSqrtPrice)
-      .accountsPartial({
-        proposal,
-        vaultAuthority,
-        maker: maker.publicKey, // Maker is the proposal creator, not chain service
-        tokenVault: vault,
-        wsolVault,
-        poolAuthority: pdas.poolAuthority, // Must be DAMM v2 pool authority PDA (const_pda::const_authority::POOL_ID)
-        dammPoolAuthority: pdas.poolAuthority,
-        poolConfig: EXPECTED_CONFIG_PUBKEY,
-        pool: pdas.pool,
-        positionNftMint: pdas.positionNftMint.publicKey,
-        positionNftAccount: pdas.positionNftAccount,
-        position: pdas.position,
-        ammProgram: cpAmm.programId,
-        baseMint: mint.publicKey,
-        mintAccount: proposalData.mintAccount,
-        makerTokenAccount: pdas.makerTokenAccount,
-        quoteMint: WSOL_MINT,
-        tokenAVault: pdas.tokenAVault,
-        tokenBVault: pdas.tokenBVault,
-        payer: chainServiceAuthority.publicKey,
-        chainServicePubkey: chainServiceAuthority.publicKey,
-        tokenBaseProgram: TOKEN_PROGRAM_ID,
-        tokenQuoteProgram: TOKEN_PROGRAM_ID,
-        token2022Program: TOKEN_2022_PROGRAM_ID,
-        dammEventAuthority: pdas.dammEventAuthority,
-        systemProgram: anchor.web3.SystemProgram.programId,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        config: configStruct,
-      })
+      .createPool(initSqrtPrice, null)
+      .accountsPartial(createPoolAccounts)
       .signers([chainServiceAuthority, pdas.positionNftMint])
       .transaction();
 
@@ -1132,68 +1442,87 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
     expect(capturedEvent.proposalAddress.toBase58()).to.equal(proposal.toBase58());
     expect(capturedEvent.mintAccount.toBase58()).to.equal(mint.publicKey.toBase58());
+    expect(capturedEvent.poolFeeSchedule.cliffFeeNumerator.toNumber()).to.equal(990_000_000);
+    expect(capturedEvent.activationType).to.deep.equal({ timestamp: {} });
+    expect(capturedEvent.activationPoint).to.be.null;
+    expect(capturedEvent.sqrtMinPrice.toString()).to.equal(minSqrtPrice.toString());
+    expect(capturedEvent.sqrtMaxPrice.toString()).to.equal(maxSqrtPrice.toString());
+
+    const launchedProposal = await program.account.proposal.fetch(proposal);
+    expect(launchedProposal.poolFeeSchedule.schedulerMode).to.deep.equal({ linear: {} });
+    expect(launchedProposal.poolFeeSchedule.numberOfPeriods).to.equal(100);
+
+    // Whatever the position did not take is recorded under the default policy
+    expect(capturedEvent.residualPolicy).to.deep.equal({ burn: {} });
+    expect(launchedProposal.residualPolicy).to.deep.equal({ burn: {} });
+    expect(capturedEvent.residualQuotePolicy).to.deep.equal({ treasury: {} });
+    expect(launchedProposal.residualQuotePolicy).to.deep.equal({ treasury: {} });
+    expect(launchedProposal.residualBase.toString()).to.equal(capturedEvent.residualBase.toString());
+    expect(launchedProposal.residualQuote.toString()).to.equal(capturedEvent.residualQuote.toString());
+    expect(
+      capturedEvent.quoteDeposited.add(capturedEvent.residualQuote).toString()
+    ).to.equal(launchedProposal.totalBacking.toString());
   });
 
   it("10.5a. Reset pool launch in isolation", async () => {
     // Create a new mint for the reset - we'll create it via createProposal to ensure proper initialization
     const newMint = anchor.web3.Keypair.generate();
+    const proposalEscrow = findProposalEscrowPDA(program.programId, proposal);
     const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
     
     // Fetch current proposal state
     const proposalDataBefore = await program.account.proposal.fetch(proposal);
     
     // Verify pool is currently launched
-    expect(proposalDataBefore.isPoolLaunched).to.be.true;
-    
-    // Calculate required amount (with generous buffer)
-    const totalBackingBN = proposalDataBefore.totalBacking instanceof BN
-      ? proposalDataBefore.totalBacking
-      : new BN(proposalDataBefore.totalBacking.toString());
-    
-    // Add extra buffer (3x the required amount) to ensure we have enough
-    const requiredAmount = totalBackingBN.mul(new BN(3));
+    expect(proposalDataBefore.status).to.deep.equal({ launched: {} });
     
-    // Get WSOL vault balance
-    let wsolBalanceBefore = new BN(0);
+    // The shared WSOL vault is never touched; the operator refunds this proposal's escrow first
+    const escrowedBacking = proposalDataBefore.totalBacking.sub(proposalDataBefore.excessRefunded);
+    const rentExemptMinimum = await provider.connection.getMinimumBalanceForRentExemption(0);
+    const escrowBalance = await provider.connection.getBalance(proposalEscrow);
+    const wsolVaultBefore = await provider.connection.getTokenAccountBalance(wsolVault);
+
     try {
-      const wsolVaultAccount = await provider.connection.getTokenAccountBalance(wsolVault);
-      wsolBalanceBefore = new BN(wsolVaultAccount.value.amount);
-    } catch (e) {
-      // Vault might not exist yet - that's fine, we'll add SOL anyway
-    }
-    
-    // If vault doesn't have enough WSOL, add some
-    if (wsolBalanceBefore.lt(requiredAmount)) {
-      const amountToAdd = requiredAmount.sub(wsolBalanceBefore);
-      
-      // Transfer SOL directly to WSOL vault (wraps it automatically when synced)
-      const transferIx = anchor.web3.SystemProgram.transfer({
-        fromPubkey: provider.wallet.publicKey,
-        toPubkey: wsolVault,
-        lamports: amountToAdd.toNumber(),
-      });
-      
-      // Sync native to wrap SOL to WSOL
-      const syncIx = createSyncNativeInstruction(wsolVault);
-      
-      await provider.sendAndConfirm(
-        new anchor.web3.Transaction().add(transferIx, syncIx)
-      ).then(confirm);
-      
-      console.log(`Added ${amountToAdd.toString()} lamports (${amountToAdd.div(new BN(1e9)).toString()} SOL) to WSOL vault`);
+      await program.methods
+        .resetPoolLaunch()
+        .accountsPartial({
+          authority: chainServiceAuthority.publicKey,
+          payer: chainServiceAuthority.publicKey,
+          proposal,
+          vaultAuthority,
+          proposalEscrow,
+          mintAccount: newMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([chainServiceAuthority, newMint])
+        .rpc();
+
+      assert.fail('Should not allow a reset before the escrow is refunded');
+    } catch (err) {
+      expect(err.message).to.include('EscrowBalanceMismatch');
     }
+
+    const shortfall = escrowedBacking.addn(rentExemptMinimum).subn(escrowBalance);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: provider.wallet.publicKey,
+          toPubkey: proposalEscrow,
+          lamports: shortfall.toNumber(),
+        })
+      )
+    ).then(confirm);
     
-    // Call reset_pool_launch - this will initialize the new mint account
     // Use chainServiceAuthority like test 10 (Launches coin and creates DAMM pool)
     await program.methods
       .resetPoolLaunch()
-      .accounts({
+      .accountsPartial({
         authority: chainServiceAuthority.publicKey,
         payer: chainServiceAuthority.publicKey,
         proposal,
         vaultAuthority,
-        wsolVault,
-        quoteMint: WSOL_MINT,
+        proposalEscrow,
         mintAccount: newMint.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -1201,12 +1530,21 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
       .signers([chainServiceAuthority, newMint])
       .rpc()
       .then(confirm);
+
+    const wsolVaultAfter = await provider.connection.getTokenAccountBalance(wsolVault);
+    expect(wsolVaultAfter.value.amount).to.equal(wsolVaultBefore.value.amount);
     
     // Verify proposal state after reset
     const proposalDataAfter = await program.account.proposal.fetch(proposal);
-    expect(proposalDataAfter.isPoolLaunched).to.be.false;
+    expect(proposalDataAfter.status).to.deep.equal({ succeeded: {} });
     expect(proposalDataAfter.mintAccount.toBase58()).to.equal(newMint.publicKey.toBase58());
     expect(proposalDataAfter.launchTimestamp).to.be.null;
+    expect(proposalDataAfter.poolAddress).to.be.null;
+    expect(proposalDataAfter.poolPosition).to.be.null;
+    expect(proposalDataAfter.poolFeeSchedule).to.be.null;
+    expect(proposalDataAfter.residualPolicy).to.be.null;
+    expect(proposalDataAfter.residualBase.toString()).to.equal('0');
+    expect(proposalDataAfter.poolSqrtMaxPrice.toString()).to.equal('0');
   });
 
   it("10.5b. Run pool creation logic again after reset", async () => {
@@ -1220,7 +1558,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
     
     // Verify pool is not launched (should be reset from 10.5a)
-    expect(proposalDataBefore.isPoolLaunched).to.be.false;
+    expect(proposalDataBefore.status).to.deep.equal({ succeeded: {} });
     
     // Derive new pool PDAs for the current mint
     const newPdas = derivePoolPDAs(program.programId, cpAmm.programId, currentMint, WSOL_MINT, maker.publicKey, config);
@@ -1260,7 +1598,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     const { blockhash } = await provider.connection.getLatestBlockhash('confirmed');
     
     const tx2 = await program.methods
-      .createPool(initSqrtPrice)
+      .createPool(initSqrtPrice, null)
       .accountsPartial({
         proposal,
         vaultAuthority,
@@ -1287,6 +1625,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         tokenQuoteProgram: TOKEN_PROGRAM_ID,
         token2022Program: TOKEN_2022_PROGRAM_ID,
         dammEventAuthority: newPdas.dammEventAuthority,
+        weweTreasury,
         systemProgram: anchor.web3.SystemProgram.programId,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         config: configStruct,
@@ -1341,7 +1680,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     
     // Verify proposal is marked as launched again
     const proposalDataFinal = await program.account.proposal.fetch(proposal);
-    expect(proposalDataFinal.isPoolLaunched).to.be.true;
+    expect(proposalDataFinal.status).to.deep.equal({ launched: {} });
     expect(proposalDataFinal.mintAccount.toBase58()).to.equal(currentMint.toBase58());
     expect(proposalDataFinal.launchTimestamp).to.not.be.null;
     
@@ -1490,47 +1829,11 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
   });
   
   it('16. Claims position fee and distributes tokens', async () => {
-    const weweTreasury = new anchor.web3.PublicKey("76U9hvHNUNn7YV5FekSzDHzqnHETsUpDKq4cMj2dMxNi");
-    const weweWsolAccount = getAssociatedTokenAddressSync(WSOL_MINT, weweTreasury, true);
-    const weweTokenAccount = getAssociatedTokenAddressSync(mint.publicKey, weweTreasury, true);
-    const makerWsolAccount = getAssociatedTokenAddressSync(WSOL_MINT, maker.publicKey, true);
-    const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
-    const treasuryTempWsol = findTempWsolPDA(vaultAuthority, proposal, true);
-    const makerTempWsol = findTempWsolPDA(vaultAuthority, proposal, false);
     const computeUnitsIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
 
-    const tx = await program.methods
+    await program.methods
       .claimPoolFee()
-      .accounts({
-        poolAuthority: pdas.poolAuthority,
-        payer: authority.publicKey,
-        maker: maker.publicKey,
-        weweTreasury,
-        proposal,
-        vaultAuthority,
-        weweWsolAccount,
-        weweTokenAccount,
-        makerWsolAccount,
-        makerTokenAccount: pdas.makerTokenAccount,
-        pool: pdas.pool,
-        position: pdas.position,
-        tokenAAccount: vault,
-        tokenBAccount: wsolVault,
-        tokenAVault: pdas.tokenAVault,
-        tokenBVault: pdas.tokenBVault,
-        tokenAMint: mint.publicKey,
-        tokenBMint: WSOL_MINT,
-        positionNftAccount: pdas.positionNftAccount,
-        treasuryTempWsol,
-        makerTempWsol,
-        tokenAProgram: TOKEN_PROGRAM_ID,
-        tokenBProgram: TOKEN_PROGRAM_ID,
-        ammProgram: cpAmm.programId,
-        eventAuthority: pdas.dammEventAuthority,
-        systemProgram: anchor.web3.SystemProgram.programId,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      })
+      .accountsPartial(await getClaimPoolFeeAccounts(proposal, authority.publicKey))
       .signers([authority])
       .preInstructions([computeUnitsIx])
       .rpc()
@@ -1807,7 +2110,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Create proposal
       await program.methods
-        .createProposal(metadata.name, metadata.symbol, metadata.uri)
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, LAUNCH_BACKING_DURATION, null)
         .accountsPartial({
           payer: authority.publicKey,
           maker: maker.publicKey,
@@ -1825,7 +2128,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Back proposal
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: testBackerBurn1.publicKey,
         weweVault: weweTreasury,
@@ -1841,13 +2144,12 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .rpc()
         .then(confirm);
 
+      await finalizeAfterBacking(testProposalBurn1);
+
       // Launch pool
       const config_account = await cpAmm.account.config.fetch(config);
-      // Calculate price: quoteAmount (1 lamport) / baseAmount (150M tokens * 10^9)
-      const baseAmount = new BN(150_000_000).mul(new BN(10).pow(new BN(9)));
-      const quoteAmount = new BN(1);
-      const price = new Decimal(quoteAmount.toString()).div(new Decimal(baseAmount.toString())).toString();
-      const sqrtPrice = getSqrtPriceFromPrice(price, 9, 9);
+      // Launch at the price implied by the raise
+      const sqrtPrice = await getLaunchSqrtPrice(testProposalBurn1);
       const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
       const pdasBurn1 = derivePoolPDAs(program.programId, cpAmm.programId, testMintBurn1.publicKey, WSOL_MINT, maker.publicKey, config);
 
@@ -1858,7 +2160,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
       const computeUnitsIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
       const tx = new anchor.web3.Transaction().add(computeUnitsIx);
       const createPoolIx = await program.methods
-        .createPool(sqrtPrice)
+        .createPool(sqrtPrice, null)
         .accountsPartial({
           proposal: testProposalBurn1,
           vaultAuthority,
@@ -1885,6 +2187,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
           tokenQuoteProgram: TOKEN_PROGRAM_ID,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           dammEventAuthority: pdasBurn1.dammEventAuthority,
+          weweTreasury,
           systemProgram: anchor.web3.SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           config: configStruct
@@ -1973,6 +2276,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
           vaultAuthority,
           tokenVault: testVaultBurn1,
           tokenProgram: TOKEN_PROGRAM_ID,
+          config: configStruct,
         })
         .signers([authority])
         .rpc()
@@ -2034,7 +2338,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Create proposal
       await program.methods
-        .createProposal(metadata.name, metadata.symbol, metadata.uri)
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, LAUNCH_BACKING_DURATION, null)
         .accountsPartial({
           payer: authority.publicKey,
           maker: maker.publicKey,
@@ -2052,7 +2356,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Back proposal
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: testBackerBurn2.publicKey,
         weweVault: weweTreasury,
@@ -2068,13 +2372,12 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .rpc()
         .then(confirm);
 
+      await finalizeAfterBacking(testProposalBurn2);
+
       // Launch pool
       const config_account = await cpAmm.account.config.fetch(config);
-      // Calculate price: quoteAmount (1 lamport) / baseAmount (150M tokens * 10^9)
-      const baseAmount = new BN(150_000_000).mul(new BN(10).pow(new BN(9)));
-      const quoteAmount = new BN(1);
-      const price = new Decimal(quoteAmount.toString()).div(new Decimal(baseAmount.toString())).toString();
-      const sqrtPrice = getSqrtPriceFromPrice(price, 9, 9);
+      // Launch at the price implied by the raise
+      const sqrtPrice = await getLaunchSqrtPrice(testProposalBurn2);
       const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
       const pdasBurn2 = derivePoolPDAs(program.programId, cpAmm.programId, testMintBurn2.publicKey, WSOL_MINT, maker.publicKey, config);
       const destAccountBurn2 = pdasBurn2.makerTokenAccount; // Use maker's ATA for the test mint as destination
@@ -2086,7 +2389,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
       const computeUnitsIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
       const tx = new anchor.web3.Transaction().add(computeUnitsIx);
       const createPoolIx = await program.methods
-        .createPool(sqrtPrice)
+        .createPool(sqrtPrice, null)
         .accountsPartial({
           proposal: testProposalBurn2,
           vaultAuthority,
@@ -2113,6 +2416,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
           tokenQuoteProgram: TOKEN_PROGRAM_ID,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           dammEventAuthority: pdasBurn2.dammEventAuthority,
+          weweTreasury,
           systemProgram: anchor.web3.SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           config: configStruct
@@ -2231,6 +2535,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
           vaultAuthority,
           tokenVault: testVaultBurn2,
           tokenProgram: TOKEN_PROGRAM_ID,
+          config: configStruct,
         })
         .signers([authority])
         .rpc()
@@ -2265,19 +2570,25 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
   describe('Access Control Tests', () => {
     it('17. Fails when unauthorized user tries to set config', async () => {
       const unauthorizedUser = anchor.web3.Keypair.generate();
-      const refundFeeBps = new BN(200); // 200 BPS = 2%
       
       try {
         await program.methods
           .setConfig(
-            new BN(1_000_000_000),
+            MIN_AMOUNT_PER_USER,
+            MAX_AMOUNT_PER_USER,
             new BN(1_000_000_000),
             new BN(150_000_000),
             new BN(10_000_000),
             new BN(140_000_000),
             new BN(1),
             new BN(3),
-            refundFeeBps, // refund_fee_basis_points: 200 BPS = 2%
+            REFUND_FEES, // refund_fees
+            { depositWeighted: {} }, // allocation_mode
+            MIN_BACKING_DURATION,
+            MAX_BACKING_DURATION,
+            WITHDRAWAL_LOCKOUT_SECONDS,
+            SQRT_PRICE_TOLERANCE_BPS,
+            CONFIG_UPDATE_DELAY,
           )
           .accounts({
             authority: unauthorizedUser.publicKey,
@@ -2292,51 +2603,68 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
       }
     });
 
-    it('17a. configure_authority can set config', async () => {
-      const amountToRaisePerUser = new BN(1_000_000_000); // 1 SOL
+    it('17a. configure_authority cannot overwrite an initialized config', async () => {
+      const minAmountPerUser = MIN_AMOUNT_PER_USER;
+      const maxAmountPerUser = MAX_AMOUNT_PER_USER;
       const totalMint = new BN(1_000_000_000);
       const totalPoolTokens = new BN(150_000_000);
       const makerTokenAmount = new BN(10_000_000);
       const totalAirdropAmountPerMilestone = new BN(140_000_000);
       const minBackers = new BN(1);
       const maxBackedProposals = new BN(3);
-      const refundFeeBps = new BN(200); // 200 BPS = 2%
       
-      const tx = await program.methods
-        .setConfig(
-          amountToRaisePerUser,
-          totalMint,
-          totalPoolTokens,
-          makerTokenAmount,
-          totalAirdropAmountPerMilestone,
-          minBackers,
-          maxBackedProposals,
-          refundFeeBps,
-        )
-        .accounts({
-          authority: configureAuthority.publicKey,
-          config: configStruct,
-        })
-        .signers([configureAuthority])
-        .rpc();
+      try {
+        await program.methods
+          .setConfig(
+            minAmountPerUser,
+            maxAmountPerUser,
+            totalMint,
+            totalPoolTokens,
+            makerTokenAmount,
+            totalAirdropAmountPerMilestone,
+            minBackers,
+            maxBackedProposals,
+            REFUND_FEES, // refund_fees
+            { depositWeighted: {} }, // allocation_mode
+            MIN_BACKING_DURATION,
+            MAX_BACKING_DURATION,
+            WITHDRAWAL_LOCKOUT_SECONDS,
+            SQRT_PRICE_TOLERANCE_BPS,
+            CONFIG_UPDATE_DELAY,
+          )
+          .accounts({
+            authority: configureAuthority.publicKey,
+            config: configStruct,
+          })
+          .signers([configureAuthority])
+          .rpc();
 
-      await confirm(tx);
+        assert.fail('Should not allow set_config to overwrite the config');
+      } catch (err) {
+        expect(err.message).to.include('ConfigAlreadyInitialized');
+      }
     });
 
     it('17b. chain_service cannot set config', async () => {
-      const refundFeeBps = new BN(200); // 200 BPS = 2%
       
       try {
         await program.methods
           .setConfig(
-            new BN(1_000_000_000),
+            MIN_AMOUNT_PER_USER,
+            MAX_AMOUNT_PER_USER,
             new BN(1_000_000_000),
             new BN(150_000_000),
             new BN(10_000_000),
             new BN(140_000_000),
             new BN(1),
             new BN(3),
-            refundFeeBps, // refund_fee_basis_points: 200 BPS = 2%
+            REFUND_FEES, // refund_fees
+            { depositWeighted: {} }, // allocation_mode
+            MIN_BACKING_DURATION,
+            MAX_BACKING_DURATION,
+            WITHDRAWAL_LOCKOUT_SECONDS,
+            SQRT_PRICE_TOLERANCE_BPS,
+            CONFIG_UPDATE_DELAY,
           )
           .accounts({
             authority: chainServiceAuthority.publicKey,
@@ -2351,6 +2679,66 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
       }
     });
 
+    it('17c. Role transfer requires the proposed key to accept', async () => {
+      const [adminRegistry] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from('admin_registry')],
+        program.programId
+      );
+
+      await program.methods
+        .proposeRoleTransfer({ pauser: {} }, authority.publicKey)
+        .accounts({
+          authority: configureAuthority.publicKey,
+        })
+        .signers([configureAuthority])
+        .rpc()
+        .then(confirm);
+
+      // Anyone other than the pending key cannot accept
+      try {
+        await program.methods
+          .acceptRoleTransfer({ pauser: {} })
+          .accounts({
+            newHolder: maker.publicKey,
+          })
+          .signers([maker])
+          .rpc();
+
+        assert.fail('Should not allow a non-pending key to accept the role');
+      } catch (err) {
+        expect(err.message).to.include('NotOwner');
+      }
+
+      await program.methods
+        .acceptRoleTransfer({ pauser: {} })
+        .accounts({
+          newHolder: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      const registry = await program.account.adminRegistry.fetch(adminRegistry);
+      expect(registry.pauser.toBase58()).to.equal(authority.publicKey.toBase58());
+      expect(registry.pendingPauser).to.be.null;
+    });
+
+    it('17d. migrate_config rejects a config already in the current layout', async () => {
+      try {
+        await program.methods
+          .migrateConfig(SQRT_PRICE_TOLERANCE_BPS, CONFIG_UPDATE_DELAY, null)
+          .accounts({
+            authority: configureAuthority.publicKey,
+          })
+          .signers([configureAuthority])
+          .rpc();
+
+        assert.fail('Should not migrate a config twice');
+      } catch (err) {
+        expect(err.message).to.include('ConfigAlreadyMigrated');
+      }
+    });
+
     it('18. Fails when unauthorized user tries to reject proposal', async () => {
       const unauthorizedUser = anchor.web3.Keypair.generate();
 
@@ -2481,47 +2869,11 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
     it('23. Fails when unauthorized user tries to claim pool fees', async () => {
       const unauthorizedUser = anchor.web3.Keypair.generate();
-      const weweTreasury = new anchor.web3.PublicKey("76U9hvHNUNn7YV5FekSzDHzqnHETsUpDKq4cMj2dMxNi");
-      const weweWsolAccount = getAssociatedTokenAddressSync(WSOL_MINT, weweTreasury, true);
-      const weweTokenAccount = getAssociatedTokenAddressSync(mint.publicKey, weweTreasury, true);
-      const makerWsolAccount = getAssociatedTokenAddressSync(WSOL_MINT, maker.publicKey, true);
-      const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
-      const treasuryTempWsol = findTempWsolPDA(vaultAuthority, proposal, true);
-      const makerTempWsol = findTempWsolPDA(vaultAuthority, proposal, false);
 
       try {
         await program.methods
           .claimPoolFee()
-          .accounts({
-            poolAuthority: pdas.poolAuthority,
-            payer: unauthorizedUser.publicKey,
-            maker: maker.publicKey,
-            weweTreasury,
-            proposal,
-            vaultAuthority,
-            weweWsolAccount,
-            weweTokenAccount,
-            makerWsolAccount,
-            makerTokenAccount: pdas.makerTokenAccount,
-            pool: pdas.pool,
-            position: pdas.position,
-            tokenAAccount: vault,
-            tokenBAccount: wsolVault,
-            tokenAVault: pdas.tokenAVault,
-            tokenBVault: pdas.tokenBVault,
-            tokenAMint: mint.publicKey,
-            tokenBMint: WSOL_MINT,
-            positionNftAccount: pdas.positionNftAccount,
-            treasuryTempWsol,
-            makerTempWsol,
-            tokenAProgram: TOKEN_PROGRAM_ID,
-            tokenBProgram: TOKEN_PROGRAM_ID,
-            ammProgram: cpAmm.programId,
-            eventAuthority: pdas.dammEventAuthority,
-            systemProgram: anchor.web3.SystemProgram.programId,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          })
+          .accountsPartial(await getClaimPoolFeeAccounts(proposal, unauthorizedUser.publicKey))
           .signers([unauthorizedUser])
           .rpc();
 
@@ -2581,7 +2933,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Create test proposal
       await program.methods
-        .createProposal(metadata.name, metadata.symbol, metadata.uri)
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, BACKING_DURATION, null)
         .accountsPartial({
           payer: authority.publicKey,
           maker: maker.publicKey,
@@ -2614,7 +2966,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
       // Try to back the rejected proposal
       try {
         await program.methods
-          .depositSol()
+          .depositSol(BACKING_AMOUNT)
           .accountsPartial({
             backer: testBacker3.publicKey,
         weweVault: weweTreasury,
@@ -2640,7 +2992,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
       const proposalData = await program.account.proposal.fetch(testProposal3);
       try {
         await program.methods
-          .depositSol()
+          .depositSol(BACKING_AMOUNT)
           .accountsPartial({
             backer: maker.publicKey,
         weweVault: weweTreasury,
@@ -2696,7 +3048,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
       // Try to back already launched proposal (proposal was launched in test #10)
       try {
         await program.methods
-          .depositSol()
+          .depositSol(BACKING_AMOUNT)
           .accountsPartial({
             backer: newBacker.publicKey,
         weweVault: weweTreasury,
@@ -2760,7 +3112,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Create new proposal
       await program.methods
-        .createProposal(metadata.name, metadata.symbol, metadata.uri)
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, BACKING_DURATION, null)
         .accountsPartial({
           payer: authority.publicKey,
           maker: maker.publicKey,
@@ -2779,7 +3131,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
       // Try to back without soulbound token
       try {
         await program.methods
-          .depositSol()
+          .depositSol(BACKING_AMOUNT)
           .accountsPartial({
             backer: userWithoutToken.publicKey,
         weweVault: weweTreasury,
@@ -2812,7 +3164,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       try {
         await program.methods
-          .createProposal(longName, metadata.symbol, metadata.uri)
+          .createProposal(longName, metadata.symbol, metadata.uri, BACKING_DURATION, null)
           .accountsPartial({
             payer: authority.publicKey,
             maker: maker.publicKey,
@@ -2843,7 +3195,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       try {
         await program.methods
-          .createProposal(metadata.name, longSymbol, metadata.uri)
+          .createProposal(metadata.name, longSymbol, metadata.uri, BACKING_DURATION, null)
           .accountsPartial({
             payer: authority.publicKey,
             maker: maker.publicKey,
@@ -2874,7 +3226,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       try {
         await program.methods
-          .createProposal(metadata.name, metadata.symbol, longUri)
+          .createProposal(metadata.name, metadata.symbol, longUri, BACKING_DURATION, null)
           .accountsPartial({
             payer: authority.publicKey,
             maker: maker.publicKey,
@@ -2894,6 +3246,36 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         expect(err.message).to.include('LenthTooLong');
       }
     });
+
+    it('30.5. Fails when creating proposal with backing duration outside config bounds', async () => {
+      const makerData = await program.account.makerAccount.fetch(makerAccount);
+      const currentProposalIndex = makerData.proposalCount;
+      const testProposal8 = findProposalPDA(program.programId, maker.publicKey, currentProposalIndex);
+      const testMint8 = anchor.web3.Keypair.generate();
+      const [testVault8] = getTokenVaultAddress(vaultAuthority, testMint8.publicKey, program.programId);
+
+      try {
+        await program.methods
+          .createProposal(metadata.name, metadata.symbol, metadata.uri, MAX_BACKING_DURATION.addn(1), null)
+          .accountsPartial({
+            payer: authority.publicKey,
+            maker: maker.publicKey,
+            makerAccount,
+            vaultAuthority,
+            proposal: testProposal8,
+            mintAccount: testMint8.publicKey,
+            tokenVault: testVault8,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            config: configStruct
+          })
+          .signers([authority, testMint8, maker])
+          .rpc();
+
+        assert.fail('Should not allow backing duration above the configured maximum');
+      } catch (err) {
+        expect(err.message).to.include('InvalidBackingDuration');
+      }
+    });
   }); 
 
   describe('Double Operation Prevention Tests', () => {
@@ -2954,6 +3336,8 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
     let testMint8: anchor.web3.Keypair;
     let testVault8: anchor.web3.PublicKey;
     let proposalIndex8;
+    let testBacker8: anchor.web3.Keypair;
+    let testBackerAccount8: anchor.web3.PublicKey;
 
     before(async () => {
       const makerData = await program.account.makerAccount.fetch(makerAccount);
@@ -2965,7 +3349,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Create test proposal
       await program.methods
-        .createProposal(metadata.name, metadata.symbol, metadata.uri)
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, BACKING_DURATION, null)
         .accountsPartial({
           payer: authority.publicKey,
           maker: maker.publicKey,
@@ -2982,13 +3366,10 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .then(confirm);
     });
 
-    it('33. Fails when trying to launch pool with insufficient backers', async () => {
+    it('33. Fails when trying to launch pool before the proposal is finalized', async () => {
       const config_account = await cpAmm.account.config.fetch(config);
-      // Calculate price: quoteAmount (1 lamport) / baseAmount (150M tokens * 10^9)
-      const baseAmount = new BN(150_000_000).mul(new BN(10).pow(new BN(9)));
-      const quoteAmount = new BN(1);
-      const price = new Decimal(quoteAmount.toString()).div(new Decimal(baseAmount.toString())).toString();
-      const sqrtPrice = getSqrtPriceFromPrice(price, 9, 9);
+      // Launch at the price implied by the raise
+      const sqrtPrice = await getLaunchSqrtPrice(testProposal8);
       const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
       const pdas8 = derivePoolPDAs(program.programId, cpAmm.programId, testMint8.publicKey, WSOL_MINT, maker.publicKey, config);
       const testProposal8Data = await program.account.proposal.fetch(testProposal8);
@@ -2998,7 +3379,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       try {
         await program.methods
-          .createPool(sqrtPrice)
+          .createPool(sqrtPrice, null)
           .accountsPartial({
             proposal: testProposal8,
             vaultAuthority,
@@ -3025,6 +3406,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
             tokenQuoteProgram: TOKEN_PROGRAM_ID,
             token2022Program: TOKEN_2022_PROGRAM_ID,
             dammEventAuthority: pdas8.dammEventAuthority,
+            weweTreasury,
             systemProgram: anchor.web3.SystemProgram.programId,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             config: configStruct,
@@ -3032,9 +3414,9 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
           .signers([authority, pdas8.positionNftMint])
           .rpc();
 
-        assert.fail('Should not allow launching with insufficient backers');
+        assert.fail('Should not allow launching a proposal still in its backing window');
       } catch (err) {
-        expect(err.message).to.include('TargetNotMet');
+        expect(err.message).to.include('ProposalNotSucceeded');
       }
     });
 
@@ -3060,7 +3442,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
         assert.fail('Should not allow starting milestone before launch');
       } catch (err) {
-        expect(err.message).to.include('TargetNotMet');
+        expect(err.message).to.include('IllegalStateTransition');
       }
     });
 
@@ -3087,7 +3469,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .then(confirm);
       const testBackerAccount = findBackerAccountPDA(program.programId, testProposal8, testBacker.publicKey);
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: testBacker.publicKey,
         weweVault: weweTreasury,
@@ -3151,7 +3533,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .then(confirm);
       const testBackerAccount = findBackerAccountPDA(program.programId, testProposal8, testBacker.publicKey);
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: testBacker.publicKey,
         weweVault: weweTreasury,
@@ -3216,8 +3598,9 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .rpc()
         .then(confirm);
       const testBackerAccount = findBackerAccountPDA(program.programId, testProposal8, testBacker.publicKey);
+      testBackerAccount8 = testBackerAccount;
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: testBacker.publicKey,
         weweVault: weweTreasury,
@@ -3254,6 +3637,63 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         expect(err.message).to.include('BackingNotEnded');
       }
     });
+
+    it('37.5. Fails when someone other than the maker cancels a proposal', async () => {
+      try {
+        await program.methods
+          .cancelProposal(1)
+          .accounts({
+            maker: authority.publicKey,
+            proposal: testProposal8,
+          })
+          .signers([authority])
+          .rpc();
+
+        assert.fail('Should not allow a non-maker to cancel');
+      } catch (err) {
+        expect(err.message).to.include('NotOwner');
+      }
+    });
+
+    it('37.6. Maker cancels a proposal and backers are refunded without fee', async () => {
+      const eventPromise = waitForEvent(program, 'proposalCancelled');
+
+      await program.methods
+        .cancelProposal(1)
+        .accounts({
+          maker: maker.publicKey,
+          proposal: testProposal8,
+        })
+        .signers([maker])
+        .rpc()
+        .then(confirm);
+
+      const cancelledEvent = await eventPromise;
+      expect(cancelledEvent.proposalAddress.toBase58()).to.equal(testProposal8.toBase58());
+      expect(cancelledEvent.reason).to.equal(1);
+
+      const proposalData = await program.account.proposal.fetch(testProposal8);
+      expect(proposalData.status).to.deep.equal({ cancelled: {} });
+
+      const refundPromise = waitForEvent(program, 'backerRefunded');
+      await program.methods
+        .refund()
+        .accountsPartial({
+          backer: testBacker8.publicKey,
+          proposal: testProposal8,
+          weweTreasury,
+          backerAccount: testBackerAccount8,
+          backerProposalCount: findBackerProposalCountPDA(program.programId, testBacker8.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+          config: configStruct,
+        })
+        .rpc()
+        .then(confirm);
+
+      const refundEvent = await refundPromise;
+      expect(refundEvent.weweFee.toString()).to.equal('0');
+      expect(refundEvent.refundAmount.toString()).to.equal(BACKING_AMOUNT.toString());
+    });
   }); 
 
   describe('Milestone State Validation Tests', () => {
@@ -3297,7 +3737,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Create proposal
       await program.methods
-        .createProposal(metadata.name, metadata.symbol, metadata.uri)
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, LAUNCH_BACKING_DURATION, null)
         .accountsPartial({
           payer: authority.publicKey,
           maker: maker.publicKey,
@@ -3315,7 +3755,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Back proposal
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: testBacker9.publicKey,
         weweVault: weweTreasury,
@@ -3331,13 +3771,12 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .rpc()
         .then(confirm);
 
+      await finalizeAfterBacking(testProposal9);
+
       // Launch pool
       const config_account = await cpAmm.account.config.fetch(config);
-      // Calculate price: quoteAmount (1 lamport) / baseAmount (150M tokens * 10^9)
-      const baseAmount = new BN(150_000_000).mul(new BN(10).pow(new BN(9)));
-      const quoteAmount = new BN(1);
-      const price = new Decimal(quoteAmount.toString()).div(new Decimal(baseAmount.toString())).toString();
-      const sqrtPrice = getSqrtPriceFromPrice(price, 9, 9);
+      // Launch at the price implied by the raise
+      const sqrtPrice = await getLaunchSqrtPrice(testProposal9);
       const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
       const pdas9 = derivePoolPDAs(program.programId, cpAmm.programId, testMint9.publicKey, WSOL_MINT, maker.publicKey, config);
       const testProposal9Data = await program.account.proposal.fetch(testProposal9);
@@ -3347,7 +3786,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       const computeUnitsIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
       const tx = await program.methods
-        .createPool(sqrtPrice)
+        .createPool(sqrtPrice, null)
         .accountsPartial({
           proposal: testProposal9,
           vaultAuthority,
@@ -3374,6 +3813,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
           tokenQuoteProgram: TOKEN_PROGRAM_ID,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           dammEventAuthority: pdas9.dammEventAuthority,
+          weweTreasury,
           systemProgram: anchor.web3.SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           config: configStruct,
@@ -3445,7 +3885,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Create and reject proposal
       await program.methods
-        .createProposal(metadata.name, metadata.symbol, metadata.uri)
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, BACKING_DURATION, null)
         .accountsPartial({
           payer: authority.publicKey,
           maker: maker.publicKey,
@@ -3492,7 +3932,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
         assert.fail('Should not allow starting milestone on rejected proposal');
       } catch (err) {
-        expect(err.message).to.include('ProposalRejected');
+        expect(err.message).to.include('IllegalStateTransition');
       }
     });
   }); 
@@ -3511,17 +3951,14 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
         assert.fail('Should not allow rejecting already launched proposal');
       } catch (err) {
-        expect(err.message).to.include('PoolAlreadyLaunched');
+        expect(err.message).to.include('IllegalStateTransition');
       }
     });
 
     it('42. Fails when trying to launch already launched pool', async () => {
       const config_account = await cpAmm.account.config.fetch(config);
-      // Calculate price: quoteAmount (1 lamport) / baseAmount (150M tokens * 10^9)
-      const baseAmount = new BN(150_000_000).mul(new BN(10).pow(new BN(9)));
-      const quoteAmount = new BN(1);
-      const price = new Decimal(quoteAmount.toString()).div(new Decimal(baseAmount.toString())).toString();
-      const sqrtPrice = getSqrtPriceFromPrice(price, 9, 9);
+      // Launch at the price implied by the raise
+      const sqrtPrice = await getLaunchSqrtPrice(proposal);
       const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
       const proposalDataAlreadyLaunched = await program.account.proposal.fetch(proposal);
       const currentMint = proposalDataAlreadyLaunched.mintAccount;
@@ -3534,7 +3971,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       try {
         await program.methods
-          .createPool(sqrtPrice)
+          .createPool(sqrtPrice, null)
           .accountsPartial({
             proposal, // Already launched
             vaultAuthority,
@@ -3561,6 +3998,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
             tokenQuoteProgram: TOKEN_PROGRAM_ID,
             token2022Program: TOKEN_2022_PROGRAM_ID,
             dammEventAuthority: currentPdas.dammEventAuthority,
+            weweTreasury,
             systemProgram: anchor.web3.SystemProgram.programId,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             config: configStruct,
@@ -3589,7 +4027,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         testBackerAccount15 = findBackerAccountPDA(program.programId, testProposal15, backer.publicKey);
 
         await program.methods
-            .createProposal(metadata.name, metadata.symbol, metadata.uri)
+            .createProposal(metadata.name, metadata.symbol, metadata.uri, LAUNCH_BACKING_DURATION, null)
             .accountsPartial({
                 payer: authority.publicKey,
                 maker: maker.publicKey,
@@ -3607,7 +4045,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
         const backerAta = findUserAta(backer.publicKey, mintAccount); 
         await program.methods
-            .depositSol()
+            .depositSol(BACKING_AMOUNT)
             .accountsPartial({
                 backer: backer.publicKey,
         weweVault: weweTreasury,
@@ -3623,6 +4061,8 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
             .rpc()
             .then(confirm);
 
+        await finalizeAfterBacking(testProposal15);
+
         const testPoolPdas = derivePoolPDAs(program.programId, cpAmm.programId, testMint15.publicKey, WSOL_MINT, maker.publicKey, config);
         const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
         const config_account = await cpAmm.account.config.fetch(config);
@@ -3632,14 +4072,11 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
           : config_account.poolCreatorAuthority;
 
         const computeUnitsIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
-        // Calculate price: quoteAmount (1 lamport) / baseAmount (150M tokens * 10^9)
-        const baseAmount = new BN(150_000_000).mul(new BN(10).pow(new BN(9)));
-        const quoteAmount = new BN(1);
-        const price = new Decimal(quoteAmount.toString()).div(new Decimal(baseAmount.toString())).toString();
-        const sqrtPrice = getSqrtPriceFromPrice(price, 9, 9);
+        // Launch at the price implied by the raise
+        const sqrtPrice = await getLaunchSqrtPrice(testProposal15);
 
         const tx = await program.methods
-            .createPool(sqrtPrice)
+            .createPool(sqrtPrice, null)
             .accountsPartial({
                 proposal: testProposal15, // Use the new proposal
                 vaultAuthority,
@@ -3666,6 +4103,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
                 tokenQuoteProgram: TOKEN_PROGRAM_ID,
                 token2022Program: TOKEN_2022_PROGRAM_ID,
                 dammEventAuthority: testPoolPdas.dammEventAuthority,
+                weweTreasury,
                 systemProgram: anchor.web3.SystemProgram.programId,
                 associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
                 config: configStruct,
@@ -3811,7 +4249,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .then(confirm); 
 
       await program.methods
-        .createProposal(metadata.name, metadata.symbol, metadata.uri)
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, LAUNCH_BACKING_DURATION, null)
         .accountsPartial({
           payer: authority.publicKey,
           maker: maker.publicKey,
@@ -3829,7 +4267,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Back with both backers
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: testBacker11a.publicKey,
         weweVault: weweTreasury,
@@ -3846,7 +4284,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .then(confirm); 
 
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: testBacker11b.publicKey,
         weweVault: weweTreasury,
@@ -3862,19 +4300,18 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .rpc()
         .then(confirm); 
 
+      await finalizeAfterBacking(testProposal11);
+
       const config_account = await cpAmm.account.config.fetch(config);
-      // Calculate price: quoteAmount (1 lamport) / baseAmount (150M tokens * 10^9)
-      const baseAmount = new BN(150_000_000).mul(new BN(10).pow(new BN(9)));
-      const quoteAmount = new BN(1);
-      const price = new Decimal(quoteAmount.toString()).div(new Decimal(baseAmount.toString())).toString();
-      const sqrtPrice = getSqrtPriceFromPrice(price, 9, 9);
+      // Launch at the price implied by the raise
+      const sqrtPrice = await getLaunchSqrtPrice(testProposal11);
       const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
       const pdas11 = derivePoolPDAs(program.programId, cpAmm.programId, testMint11.publicKey, WSOL_MINT, maker.publicKey, config);
       const testProposal11DataForPool = await program.account.proposal.fetch(testProposal11);
 
       const computeUnitsIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
       const tx = await program.methods
-        .createPool(sqrtPrice)
+        .createPool(sqrtPrice, null)
         .accountsPartial({
           proposal: testProposal11,
           vaultAuthority,
@@ -3900,6 +4337,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
           tokenQuoteProgram: TOKEN_PROGRAM_ID,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           dammEventAuthority: pdas11.dammEventAuthority,
+          weweTreasury,
           systemProgram: anchor.web3.SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           config: configStruct,
@@ -4032,7 +4470,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Create proposal
       await program.methods
-        .createProposal(metadata.name, metadata.symbol, metadata.uri)
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, LAUNCH_BACKING_DURATION, null)
         .accountsPartial({
           payer: authority.publicKey,
           maker: maker.publicKey,
@@ -4050,7 +4488,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Back proposal
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: testBacker12.publicKey,
         weweVault: weweTreasury,
@@ -4066,20 +4504,19 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .rpc()
         .then(confirm); 
 
+      await finalizeAfterBacking(testProposal12);
+
       // Launch pool
       const config_account = await cpAmm.account.config.fetch(config);
-      // Calculate price: quoteAmount (1 lamport) / baseAmount (150M tokens * 10^9)
-      const baseAmount = new BN(150_000_000).mul(new BN(10).pow(new BN(9)));
-      const quoteAmount = new BN(1);
-      const price = new Decimal(quoteAmount.toString()).div(new Decimal(baseAmount.toString())).toString();
-      const sqrtPrice = getSqrtPriceFromPrice(price, 9, 9);
+      // Launch at the price implied by the raise
+      const sqrtPrice = await getLaunchSqrtPrice(testProposal12);
       const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
       const pdas12 = derivePoolPDAs(program.programId, cpAmm.programId, testMint12.publicKey, WSOL_MINT, maker.publicKey, config);
       const testProposal12Data = await program.account.proposal.fetch(testProposal12);
 
       const computeUnitsIx = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
       const tx = await program.methods
-        .createPool(sqrtPrice)
+        .createPool(sqrtPrice, null)
         .accountsPartial({
           proposal: testProposal12,
           vaultAuthority,
@@ -4105,6 +4542,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
           tokenQuoteProgram: TOKEN_PROGRAM_ID,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           dammEventAuthority: pdas12.dammEventAuthority,
+          weweTreasury,
           systemProgram: anchor.web3.SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           config: configStruct,
@@ -4191,7 +4629,7 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
 
       // Create proposal
       await program.methods
-        .createProposal(metadata.name, metadata.symbol, metadata.uri)
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, BACKING_DURATION, null)
         .accountsPartial({
           payer: authority.publicKey,
           maker: maker.publicKey,
@@ -4207,12 +4645,13 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .rpc()
         .then(confirm);
 
-      // Check vault_authority balance before
-      const vaultBalanceBefore = await provider.connection.getBalance(vaultAuthority);
+      // Check the proposal escrow balance before
+      const proposalEscrow13 = findProposalEscrowPDA(program.programId, testProposal13);
+      const vaultBalanceBefore = await provider.connection.getBalance(proposalEscrow13);
 
       // Back proposal
       await program.methods
-        .depositSol()
+        .depositSol(BACKING_AMOUNT)
         .accountsPartial({
           backer: testBacker13.publicKey,
         weweVault: weweTreasury,
@@ -4228,14 +4667,1684 @@ describe('Wewe Token Launch Pad - Integration Tests', () => {
         .rpc()
         .then(confirm);
 
-      const vaultBalanceAfter = await provider.connection.getBalance(vaultAuthority);
-      const configData = await program.account.configs.fetch(configStruct);
+      const vaultBalanceAfter = await provider.connection.getBalance(proposalEscrow13);
 
-      // Expected: full amount_to_raise_per_user (no upfront fee)
-      const expectedIncrease = configData.amountToRaisePerUser.toNumber();
+      // Expected: full deposited amount (no upfront fee)
+      const expectedIncrease = BACKING_AMOUNT.toNumber();
       const actualIncrease = vaultBalanceAfter - vaultBalanceBefore;
 
-      assert.strictEqual(actualIncrease, expectedIncrease, 'Vault should receive full amount');
+      assert.strictEqual(actualIncrease, expectedIncrease, 'Proposal escrow should receive full amount');
     }); 
   });
+
+  describe('Backer Withdrawal Tests', () => {
+    let withdrawProposal: anchor.web3.PublicKey;
+    let withdrawBacker: anchor.web3.Keypair;
+    let withdrawBackerAccount: anchor.web3.PublicKey;
+
+    before(async () => {
+      const makerData = await program.account.makerAccount.fetch(makerAccount);
+      withdrawProposal = findProposalPDA(program.programId, maker.publicKey, makerData.proposalCount);
+      const withdrawMint = anchor.web3.Keypair.generate();
+      const [withdrawVault] = getTokenVaultAddress(vaultAuthority, withdrawMint.publicKey, program.programId);
+      withdrawBacker = anchor.web3.Keypair.generate();
+      withdrawBackerAccount = findBackerAccountPDA(program.programId, withdrawProposal, withdrawBacker.publicKey);
+
+      await provider.sendAndConfirm(new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: provider.wallet.publicKey,
+          toPubkey: withdrawBacker.publicKey,
+          lamports: 2e9, // 2 SOL to cover deposit + transaction fees
+        })
+      )).then(confirm);
+
+      const withdrawBackerAta = findUserAta(withdrawBacker.publicKey, mintAccount);
+      await program.methods
+        .mintSoulboundToUser()
+        .accounts({
+          authority: authority.publicKey,
+          user: withdrawBacker.publicKey,
+          mint: mintAccount,
+          freezeAuthority,
+          mintAuthority,
+          userTokenAccount: withdrawBackerAta,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      await program.methods
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, BACKING_DURATION, null)
+        .accountsPartial({
+          payer: authority.publicKey,
+          maker: maker.publicKey,
+          makerAccount,
+          vaultAuthority,
+          proposal: withdrawProposal,
+          mintAccount: withdrawMint.publicKey,
+          tokenVault: withdrawVault,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          config: configStruct
+        })
+        .signers([authority, withdrawMint, maker])
+        .rpc()
+        .then(confirm);
+
+      await program.methods
+        .depositSol(BACKING_AMOUNT)
+        .accountsPartial({
+          backer: withdrawBacker.publicKey,
+          mint: mintAccount,
+          userTokenAccount: withdrawBackerAta,
+          proposal: withdrawProposal,
+          backerAccount: withdrawBackerAccount,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          config: configStruct
+        })
+        .signers([withdrawBacker])
+        .rpc()
+        .then(confirm);
+    });
+
+    it('47. Backer withdraws during the backing window and pays the exit fee', async () => {
+      const backerProposalCount = findBackerProposalCountPDA(program.programId, withdrawBacker.publicKey);
+      const countBefore = await program.account.backerProposalCount.fetch(backerProposalCount);
+      const treasuryBalanceBefore = await provider.connection.getBalance(weweTreasury);
+      const eventPromise = waitForEvent(program, 'backerRefunded');
+
+      await program.methods
+        .withdrawBacking()
+        .accountsPartial({
+          backer: withdrawBacker.publicKey,
+          proposal: withdrawProposal,
+          weweTreasury,
+          backerAccount: withdrawBackerAccount,
+          backerProposalCount,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          config: configStruct,
+        })
+        .signers([withdrawBacker])
+        .rpc()
+        .then(confirm);
+
+      const expectedFee = BACKING_AMOUNT.muln(REFUND_FEES.backerExitBps).divn(10_000);
+      const event = await eventPromise;
+      expect(event.cause).to.deep.equal({ backerExit: {} });
+      expect(event.weweFee.toString()).to.equal(expectedFee.toString());
+      expect(event.refundAmount.toString()).to.equal(BACKING_AMOUNT.sub(expectedFee).toString());
+
+      const treasuryBalanceAfter = await provider.connection.getBalance(weweTreasury);
+      expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(expectedFee.toNumber());
+
+      const proposalData = await program.account.proposal.fetch(withdrawProposal);
+      expect(proposalData.totalBackers.toNumber()).to.equal(0);
+      expect(proposalData.totalBacking.toNumber()).to.equal(0);
+      expect(proposalData.status).to.deep.equal({ backing: {} });
+
+      const countAfter = await program.account.backerProposalCount.fetch(backerProposalCount);
+      expect(countAfter.activeCount.toNumber()).to.equal(countBefore.activeCount.toNumber() - 1);
+
+      const closedBackerAccount = await provider.connection.getAccountInfo(withdrawBackerAccount);
+      expect(closedBackerAccount).to.be.null;
+    });
+  });
+
+  describe('Account Closing Tests', () => {
+    it('48. Authority marks a launched proposal as completed', async () => {
+      const eventPromise = waitForEvent(program, 'proposalCompleted');
+
+      await program.methods
+        .completeProposal()
+        .accounts({
+          authority: authority.publicKey,
+          proposal,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      const event = await eventPromise;
+      expect(event.proposalAddress.toBase58()).to.equal(proposal.toBase58());
+
+      const proposalData = await program.account.proposal.fetch(proposal);
+      expect(proposalData.status).to.deep.equal({ completed: {} });
+    });
+
+    it('49. Fails to close a completed proposal whose pool position still earns fees', async () => {
+      const proposalData = await program.account.proposal.fetch(proposal);
+
+      try {
+        await program.methods
+          .closeProposal()
+          .accountsPartial({
+            payer: provider.wallet.publicKey,
+            proposal,
+            rentPayer: proposalData.rentPayer,
+          })
+          .rpc();
+
+        assert.fail('Should not allow closing a launched proposal');
+      } catch (err) {
+        expect(err.message).to.include('PoolPositionOpen');
+      }
+    });
+
+    it('49a. Fails to close the backer fee vault while backer accounts are open', async () => {
+      const proposalData = await program.account.proposal.fetch(proposal);
+      expect(proposalData.closedBackers.toNumber()).to.be.lessThan(proposalData.totalBackers.toNumber());
+      const configData = await program.account.configs.fetch(configStruct);
+
+      try {
+        await program.methods
+          .closeBackerFeeVault()
+          .accountsPartial({
+            payer: provider.wallet.publicKey,
+            proposal,
+            vaultAuthority,
+            backerFeeVault: findBackerFeeVaultPDA(program.programId, proposal),
+            weweTreasury: configData.treasury,
+            config: configStruct,
+          })
+          .rpc();
+
+        assert.fail('Should not allow closing the backer fee vault with open backer accounts');
+      } catch (err) {
+        expect(err.message).to.include('ProposalNotClosable');
+      }
+    });
+  });
+
+  describe('Pause Tests', () => {
+    const claimAccounts = () => ({
+      backer: backer.publicKey,
+      proposal,
+      vaultAuthority,
+      mintAccount: mint.publicKey,
+      tokenVault: vault,
+      backerAccount,
+      backerTokenAccount,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    });
+
+    it('50. Fails when someone other than the pauser pauses the program', async () => {
+      try {
+        await program.methods
+          .setPaused(true)
+          .accounts({
+            authority: maker.publicKey,
+          })
+          .signers([maker])
+          .rpc();
+
+        assert.fail('Should not allow a non-pauser to pause the program');
+      } catch (err) {
+        expect(err.message).to.include('NotOwner');
+      }
+    });
+
+    it('51. Pauser pauses the program and user-facing instructions are blocked', async () => {
+      const eventPromise = waitForEvent(program, 'programPauseToggled');
+
+      await program.methods
+        .setPaused(true)
+        .accounts({
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      const event = await eventPromise;
+      expect(event.paused).to.equal(true);
+
+      try {
+        await program.methods
+          .claimMilestoneReward()
+          .accounts(claimAccounts())
+          .signers([backer])
+          .rpc();
+
+        assert.fail('Should not allow claiming while the program is paused');
+      } catch (err) {
+        expect(err.message).to.include('ProgramPaused');
+      }
+
+      await program.methods
+        .setPaused(false)
+        .accounts({
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      const configData = await program.account.configs.fetch(configStruct);
+      expect(configData.paused).to.equal(false);
+    });
+
+    it('52. Pauser freezes a single proposal', async () => {
+      const eventPromise = waitForEvent(program, 'proposalFreezeToggled');
+
+      await program.methods
+        .setProposalFrozen(true)
+        .accounts({
+          authority: authority.publicKey,
+          proposal,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      const event = await eventPromise;
+      expect(event.proposalAddress.toBase58()).to.equal(proposal.toBase58());
+      expect(event.frozen).to.equal(true);
+
+      try {
+        await program.methods
+          .claimMilestoneReward()
+          .accounts(claimAccounts())
+          .signers([backer])
+          .rpc();
+
+        assert.fail('Should not allow claiming from a frozen proposal');
+      } catch (err) {
+        expect(err.message).to.include('ProposalFrozen');
+      }
+
+      await program.methods
+        .setProposalFrozen(false)
+        .accounts({
+          authority: authority.publicKey,
+          proposal,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      const proposalData = await program.account.proposal.fetch(proposal);
+      expect(proposalData.frozen).to.equal(false);
+    });
+  });
+
+  describe('Treasury Config Tests', () => {
+    it('53. Fails when someone other than the treasury manager queues a fee split', async () => {
+      const configData = await program.account.configs.fetch(configStruct);
+
+      try {
+        await program.methods
+          .queueConfigUpdate({ poolFeeSplit: [{ makerBps: 0, treasuryBps: 10_000, backerBps: 0 }] })
+          .accountsPartial({
+            authority: maker.publicKey,
+            pendingUpdate: findConfigUpdatePDA(configData.nextConfigUpdateId),
+          })
+          .signers([maker])
+          .rpc();
+
+        assert.fail('Should not allow a non-treasury-manager to queue a fee split');
+      } catch (err) {
+        expect(err.message).to.include('NotOwner');
+      }
+    });
+
+    it('54. Treasury manager updates the pool fee split', async () => {
+      const configBefore = await program.account.configs.fetch(configStruct);
+      expect(configBefore.treasury.toBase58()).to.equal(weweTreasury.toBase58());
+      expect(configBefore.poolFeeSplit.makerBps).to.equal(5_000);
+      expect(configBefore.poolFeeSplit.treasuryBps).to.equal(5_000);
+
+      try {
+        await queueConfigUpdate({ poolFeeSplit: [{ makerBps: 4_000, treasuryBps: 4_000, backerBps: 1_000 }] });
+        assert.fail('Should not allow a split that does not add up to 10_000 bps');
+      } catch (err) {
+        expect(err.message).to.include('InvalidConfig');
+      }
+
+      const split = { makerBps: 4_000, treasuryBps: 4_000, backerBps: 2_000 };
+      await executeConfigUpdate(await queueConfigUpdate({ poolFeeSplit: [split] }));
+
+      const configAfter = await program.account.configs.fetch(configStruct);
+      expect(configAfter.poolFeeSplit).to.deep.equal(split);
+    });
+
+    it('55. Fails when a proposal override takes from the treasury share', async () => {
+      const makerData = await program.account.makerAccount.fetch(makerAccount);
+      const testProposal = findProposalPDA(program.programId, maker.publicKey, makerData.proposalCount);
+      const testMint = anchor.web3.Keypair.generate();
+      const [testVault] = getTokenVaultAddress(vaultAuthority, testMint.publicKey, program.programId);
+
+      try {
+        await program.methods
+          .createProposal(
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+            BACKING_DURATION,
+            { makerBps: 6_000, treasuryBps: 2_000, backerBps: 2_000 }
+          )
+          .accountsPartial({
+            payer: authority.publicKey,
+            maker: maker.publicKey,
+            makerAccount,
+            vaultAuthority,
+            proposal: testProposal,
+            mintAccount: testMint.publicKey,
+            tokenVault: testVault,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            config: configStruct
+          })
+          .signers([authority, testMint, maker])
+          .rpc();
+
+        assert.fail('Should not allow an override that lowers the treasury share');
+      } catch (err) {
+        expect(err.message).to.include('InvalidFeeSplit');
+      }
+    });
+  });
+
+  describe('Backer Fee Pool Tests', () => {
+    // Accounts for the backer's claim against the proposal's current mint
+    const claimBackerFeesAccounts = async () => {
+      const proposalData = await program.account.proposal.fetch(proposal);
+      const [tokenVault] = getTokenVaultAddress(vaultAuthority, proposalData.mintAccount, program.programId);
+      return {
+        backer: backer.publicKey,
+        proposal,
+        backerAccount,
+        vaultAuthority,
+        mintAccount: proposalData.mintAccount,
+        quoteMint: WSOL_MINT,
+        tokenVault,
+        backerTokenAccount: findUserAta(backer.publicKey, proposalData.mintAccount),
+        backerWsolAccount: findUserAta(backer.publicKey, WSOL_MINT),
+      };
+    };
+    const tokenBalance = async (account: anchor.web3.PublicKey): Promise<BN> => {
+      const info = await provider.connection.getAccountInfo(account);
+      if (!info) return new BN(0);
+      return new BN((await provider.connection.getTokenAccountBalance(account)).value.amount);
+    };
+
+    it('56. Fails when a backer claims fees before any were credited to the pool', async () => {
+      const proposalData = await program.account.proposal.fetch(proposal);
+      expect(proposalData.backerFeeIndexToken.toString()).to.equal('0');
+      expect(proposalData.backerFeeIndexWsol.toString()).to.equal('0');
+
+      try {
+        await program.methods
+          .claimBackerFees()
+          .accountsPartial(await claimBackerFeesAccounts())
+          .signers([backer])
+          .rpc();
+
+        assert.fail('Should not allow claiming an empty backer fee pool');
+      } catch (err) {
+        expect(err.message).to.include('NoBackerFeesToClaim');
+      }
+    });
+
+    it('56a. Backer claims their share of the fees a swap paid into the pool', async () => {
+      const proposalData = await program.account.proposal.fetch(proposal);
+      const poolData = await cpAmm.account.pool.fetch(proposalData.poolAddress);
+      const trader = provider.wallet.publicKey;
+      const traderWsol = getAssociatedTokenAddressSync(WSOL_MINT, trader);
+      const traderToken = getAssociatedTokenAddressSync(proposalData.mintAccount, trader);
+
+      // Buy the launched token with 1 SOL so the locked position earns fees
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createAssociatedTokenAccountIdempotentInstruction(trader, traderWsol, trader, WSOL_MINT),
+          createAssociatedTokenAccountIdempotentInstruction(trader, traderToken, trader, proposalData.mintAccount),
+          anchor.web3.SystemProgram.transfer({ fromPubkey: trader, toPubkey: traderWsol, lamports: 1_000_000_000 }),
+          createSyncNativeInstruction(traderWsol)
+        )
+      );
+      await cpAmm.methods
+        .swap({ amountIn: new BN(1_000_000_000), minimumAmountOut: new BN(0) })
+        .accountsPartial({
+          pool: proposalData.poolAddress,
+          inputTokenAccount: traderWsol,
+          outputTokenAccount: traderToken,
+          tokenAVault: poolData.tokenAVault,
+          tokenBVault: poolData.tokenBVault,
+          tokenAMint: poolData.tokenAMint,
+          tokenBMint: poolData.tokenBMint,
+          payer: trader,
+          tokenAProgram: TOKEN_PROGRAM_ID,
+          tokenBProgram: TOKEN_PROGRAM_ID,
+          referralTokenAccount: null,
+        })
+        .rpc()
+        .then(confirm);
+
+      await program.methods
+        .claimPoolFee()
+        .accountsPartial(await getClaimPoolFeeAccounts(proposal, authority.publicKey))
+        .signers([authority])
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 })])
+        .rpc()
+        .then(confirm);
+
+      const credited = await program.account.proposal.fetch(proposal);
+      const backerData = await program.account.backers.fetch(backerAccount);
+      const precision = new BN(1_000_000_000_000); // FEE_INDEX_PRECISION
+      const expectedToken = credited.backerFeeIndexToken
+        .sub(backerData.feeCheckpointToken)
+        .mul(backerData.depositAmount)
+        .div(precision);
+      const expectedWsol = credited.backerFeeIndexWsol
+        .sub(backerData.feeCheckpointWsol)
+        .mul(backerData.depositAmount)
+        .div(precision);
+      expect(expectedToken.add(expectedWsol).gtn(0)).to.equal(true);
+
+      const accounts = await claimBackerFeesAccounts();
+      const tokenBefore = await tokenBalance(accounts.backerTokenAccount);
+      const wsolBefore = await tokenBalance(accounts.backerWsolAccount);
+
+      await program.methods
+        .claimBackerFees()
+        .accountsPartial(accounts)
+        .signers([backer])
+        .rpc()
+        .then(confirm);
+
+      const tokenAfter = await tokenBalance(accounts.backerTokenAccount);
+      const wsolAfter = await tokenBalance(accounts.backerWsolAccount);
+      expect(tokenAfter.sub(tokenBefore).toString()).to.equal(expectedToken.toString());
+      expect(wsolAfter.sub(wsolBefore).toString()).to.equal(expectedWsol.toString());
+
+      const claimed = await program.account.proposal.fetch(proposal);
+      expect(claimed.backerFeePoolToken.toString()).to.equal(credited.backerFeePoolToken.sub(expectedToken).toString());
+      expect(claimed.backerFeePoolWsol.toString()).to.equal(credited.backerFeePoolWsol.sub(expectedWsol).toString());
+      const backerAfter = await program.account.backers.fetch(backerAccount);
+      expect(backerAfter.feeCheckpointToken.toString()).to.equal(credited.backerFeeIndexToken.toString());
+      expect(backerAfter.feeCheckpointWsol.toString()).to.equal(credited.backerFeeIndexWsol.toString());
+    });
+  });
+
+  describe('Pool Fee Schedule Tests', () => {
+    const validSchedule = () => ({
+      cliffFeeNumerator: new BN(500_000_000),
+      schedulerMode: { exponential: {} },
+      numberOfPeriods: 60,
+      periodFrequency: new BN(10),
+      reductionFactor: new BN(500),
+      dynamicFee: null,
+      collectFeeMode: 1,
+    });
+
+    it('57. Fails when the pool fee schedule is out of bounds', async () => {
+      try {
+        await queueConfigUpdate({ poolFeeSchedule: [{ ...validSchedule(), reductionFactor: new BN(10_000) }] });
+        assert.fail('Should not allow an exponential reduction of 100%');
+      } catch (err) {
+        expect(err.message).to.include('InvalidConfig');
+      }
+    });
+
+    it('57a. Fails when the dynamic fee uses a bin step DAMM v2 does not accept', async () => {
+      const dynamicFee = {
+        binStep: 1,
+        binStepU128: new BN('1844674407370955'), // 1 bps in Q64.64
+        filterPeriod: 10,
+        decayPeriod: 120,
+        reductionFactor: 5_000,
+        maxVolatilityAccumulator: 14_460,
+        variableFeeControl: 23_000,
+      };
+
+      try {
+        await queueConfigUpdate({
+          poolFeeSchedule: [{ ...validSchedule(), dynamicFee: { ...dynamicFee, binStep: 10 } }],
+        });
+        assert.fail('Should not allow a bin step other than 1 bps');
+      } catch (err) {
+        expect(err.message).to.include('InvalidConfig');
+      }
+    });
+
+    it('58. Configurator sets an exponential pool fee schedule', async () => {
+      const eventPromise = waitForEvent(program, 'configUpdated');
+
+      await executeConfigUpdate(await queueConfigUpdate({ poolFeeSchedule: [validSchedule()] }));
+
+      const event = await eventPromise;
+      expect(event.newValue.poolFeeSchedule[0].schedulerMode).to.deep.equal({ exponential: {} });
+
+      const configData = await program.account.configs.fetch(configStruct);
+      expect(configData.poolFeeSchedule.cliffFeeNumerator.toNumber()).to.equal(500_000_000);
+      expect(configData.poolFeeSchedule.numberOfPeriods).to.equal(60);
+    });
+  });
+
+  describe('Pool Activation Tests', () => {
+    it('59. Fails when the default activation delay exceeds the maximum', async () => {
+      try {
+        await queueConfigUpdate({
+          poolActivation: [{
+            activationType: { timestamp: {} },
+            defaultDelay: new BN(7_200),
+            maxDelay: new BN(3_600),
+          }],
+        });
+        assert.fail('Should not allow a default delay above the maximum');
+      } catch (err) {
+        expect(err.message).to.include('InvalidConfig');
+      }
+    });
+
+    it('60. Configurator schedules pool activation after creation', async () => {
+      const eventPromise = waitForEvent(program, 'configUpdated');
+
+      await executeConfigUpdate(
+        await queueConfigUpdate({
+          poolActivation: [{
+            activationType: { timestamp: {} },
+            defaultDelay: new BN(600),
+            maxDelay: new BN(86_400),
+          }],
+        })
+      );
+
+      const event = await eventPromise;
+      expect(event.newValue.poolActivation[0].defaultDelay.toNumber()).to.equal(600);
+
+      const configData = await program.account.configs.fetch(configStruct);
+      expect(configData.poolActivation.activationType).to.deep.equal({ timestamp: {} });
+      expect(configData.poolActivation.maxDelay.toNumber()).to.equal(86_400);
+    });
+  });
+
+  describe('Pool Price Range Tests', () => {
+    it('61. Fails when the lower price bound is above the initial price', async () => {
+      try {
+        await queueConfigUpdate({ poolPriceRange: [{ minPriceBps: 12_000, maxPriceBps: 40_000 }] });
+        assert.fail('Should not allow a lower bound above the initial price');
+      } catch (err) {
+        expect(err.message).to.include('InvalidConfig');
+      }
+    });
+
+    it('62. Configurator sets a concentrated launch range', async () => {
+      const range = { minPriceBps: 5_000, maxPriceBps: 40_000 };
+      const eventPromise = waitForEvent(program, 'configUpdated');
+
+      await executeConfigUpdate(await queueConfigUpdate({ poolPriceRange: [range] }));
+
+      const event = await eventPromise;
+      expect(event.newValue).to.deep.equal({ poolPriceRange: [range] });
+
+      const configData = await program.account.configs.fetch(configStruct);
+      expect(configData.poolPriceRange).to.deep.equal(range);
+    });
+  });
+
+  describe('Residual Policy Tests', () => {
+    it('63. Fails when a non-configurator queues a residual policy', async () => {
+      const configData = await program.account.configs.fetch(configStruct);
+
+      try {
+        await program.methods
+          .queueConfigUpdate({ residualPolicy: [{ burn: {} }] })
+          .accountsPartial({
+            authority: maker.publicKey,
+            pendingUpdate: findConfigUpdatePDA(configData.nextConfigUpdateId),
+          })
+          .signers([maker])
+          .rpc();
+
+        assert.fail('Should not allow a non-configurator to queue a residual policy');
+      } catch (err) {
+        expect(err.message).to.include('NotOwner');
+      }
+    });
+
+    it('64. Configurator sends launch residuals to the treasury', async () => {
+      const eventPromise = waitForEvent(program, 'configUpdated');
+
+      await executeConfigUpdate(await queueConfigUpdate({ residualPolicy: [{ treasury: {} }] }));
+      await executeConfigUpdate(await queueConfigUpdate({ residualQuotePolicy: [{ treasury: {} }] }));
+
+      const event = await eventPromise;
+      expect(event.newValue).to.deep.equal({ residualPolicy: [{ treasury: {} }] });
+
+      const configData = await program.account.configs.fetch(configStruct);
+      expect(configData.residualPolicy).to.deep.equal({ treasury: {} });
+      expect(configData.residualQuotePolicy).to.deep.equal({ treasury: {} });
+    });
+  });
+
+  describe('Proposal Terms Tests', () => {
+    it('65. Proposals keep the economics they were created under', async () => {
+      const configData = await program.account.configs.fetch(configStruct);
+      const proposalData = await program.account.proposal.fetch(proposal);
+
+      expect(proposalData.terms.minAmountPerUser.toString()).to.equal(MIN_AMOUNT_PER_USER.toString());
+      expect(proposalData.terms.maxAmountPerUser.toString()).to.equal(MAX_AMOUNT_PER_USER.toString());
+      expect(proposalData.terms.totalMint.toString()).to.equal(configData.totalMint.toString());
+      expect(proposalData.terms.refundFees).to.deep.equal(REFUND_FEES);
+      expect(proposalData.terms.withdrawalLockoutSeconds.toString()).to.equal(
+        WITHDRAWAL_LOCKOUT_SECONDS.toString()
+      );
+    });
+
+    it('66. A config change does not touch an existing proposal', async () => {
+      const before = await program.account.proposal.fetch(proposal);
+
+      await executeConfigUpdate(
+        await queueConfigUpdate({ maxAmountPerUser: [MAX_AMOUNT_PER_USER.muln(2)] })
+      );
+
+      const after = await program.account.proposal.fetch(proposal);
+      expect(after.terms.maxAmountPerUser.toString()).to.equal(before.terms.maxAmountPerUser.toString());
+
+      // Restore the original ticket size
+      await executeConfigUpdate(
+        await queueConfigUpdate({ maxAmountPerUser: [MAX_AMOUNT_PER_USER] })
+      );
+    });
+
+    it('66a. A launch setting change does not touch an existing proposal', async () => {
+      const configBefore = await program.account.configs.fetch(configStruct);
+      const before = await program.account.proposal.fetch(proposal);
+      expect(before.launchTerms.sqrtPriceToleranceBps).to.equal(SQRT_PRICE_TOLERANCE_BPS);
+
+      await executeConfigUpdate(
+        await queueConfigUpdate({ sqrtPriceToleranceBps: [SQRT_PRICE_TOLERANCE_BPS * 2] })
+      );
+      await executeConfigUpdate(await queueConfigUpdate({ residualPolicy: [{ burn: {} }] }));
+
+      const after = await program.account.proposal.fetch(proposal);
+      expect(after.launchTerms).to.deep.equal(before.launchTerms);
+
+      // Restore the original tolerance and residual policy
+      await executeConfigUpdate(
+        await queueConfigUpdate({ sqrtPriceToleranceBps: [SQRT_PRICE_TOLERANCE_BPS] })
+      );
+      await executeConfigUpdate(
+        await queueConfigUpdate({ residualPolicy: [configBefore.residualPolicy] })
+      );
+    });
+  });
+
+  describe('Config Update Tests', () => {
+    it('67. Fails to queue an update that breaks the supply invariant', async () => {
+      try {
+        // Pool, maker and two airdrop rounds no longer fit in the supply
+        await queueConfigUpdate({ totalMint: [new BN(400_000_000)] });
+        assert.fail('Should not allow total_mint below the allocated supply');
+      } catch (err) {
+        expect(err.message).to.include('InvalidConfig');
+      }
+    });
+
+    it('68. Fails to queue a refund fee above 100%', async () => {
+      try {
+        await queueConfigUpdate({ refundFees: [{ ...REFUND_FEES, backerExitBps: 10_001 }] });
+        assert.fail('Should not allow a refund fee above 10_000 bps');
+      } catch (err) {
+        expect(err.message).to.include('InvalidConfig');
+      }
+    });
+
+    it('69. Applied updates bump the version and report old and new values', async () => {
+      const configBefore = await program.account.configs.fetch(configStruct);
+      const pendingUpdate = await queueConfigUpdate({ minBackers: [new BN(2)] });
+      const eventPromise = waitForEvent(program, 'configUpdated');
+
+      await executeConfigUpdate(pendingUpdate);
+
+      const event = await eventPromise;
+      expect(event.configVersion.toString()).to.equal(configBefore.configVersion.addn(1).toString());
+      expect(event.oldValue.minBackers[0].toString()).to.equal(configBefore.minBackers.toString());
+      expect(event.newValue.minBackers[0].toString()).to.equal('2');
+
+      const configAfter = await program.account.configs.fetch(configStruct);
+      expect(configAfter.minBackers.toNumber()).to.equal(2);
+      expect(await provider.connection.getAccountInfo(pendingUpdate)).to.be.null;
+
+      await executeConfigUpdate(await queueConfigUpdate({ minBackers: [configBefore.minBackers] }));
+    });
+
+    it('70. Queued updates wait out the configured delay', async () => {
+      await executeConfigUpdate(await queueConfigUpdate({ configUpdateDelay: [new BN(3)] }));
+
+      const pendingUpdate = await queueConfigUpdate({ sqrtPriceToleranceBps: [100] });
+      try {
+        await executeConfigUpdate(pendingUpdate);
+        assert.fail('Should not apply an update before its delay has passed');
+      } catch (err) {
+        expect(err.message).to.include('ConfigUpdateNotReady');
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 4_000));
+      await executeConfigUpdate(pendingUpdate);
+
+      const configData = await program.account.configs.fetch(configStruct);
+      expect(configData.sqrtPriceToleranceBps).to.equal(100);
+    });
+
+    it('71. Configurator cancels a queued update', async () => {
+      const pendingUpdate = await queueConfigUpdate({ sqrtPriceToleranceBps: [SQRT_PRICE_TOLERANCE_BPS] });
+      const eventPromise = waitForEvent(program, 'configUpdateCancelled');
+
+      await program.methods
+        .cancelConfigUpdate()
+        .accountsPartial({
+          authority: configureAuthority.publicKey,
+          pendingUpdate,
+          queuedBy: configureAuthority.publicKey,
+        })
+        .signers([configureAuthority])
+        .rpc()
+        .then(confirm);
+
+      const event = await eventPromise;
+      expect(event.param.sqrtPriceToleranceBps[0]).to.equal(SQRT_PRICE_TOLERANCE_BPS);
+      expect(await provider.connection.getAccountInfo(pendingUpdate)).to.be.null;
+
+      // Restore the tolerance and drop the delay again
+      const restoreTolerance = await queueConfigUpdate({ sqrtPriceToleranceBps: [SQRT_PRICE_TOLERANCE_BPS] });
+      const restoreDelay = await queueConfigUpdate({ configUpdateDelay: [CONFIG_UPDATE_DELAY] });
+      await new Promise((resolve) => setTimeout(resolve, 4_000));
+      await executeConfigUpdate(restoreTolerance);
+      await executeConfigUpdate(restoreDelay);
+    });
+  });
+
+  describe('Launch Template Tests', () => {
+    const MICRO_TERMS = {
+      minAmountPerUser: new BN(10_000_000),
+      maxAmountPerUser: new BN(500_000_000),
+      totalMint: new BN(100_000_000),
+      totalPoolTokens: new BN(15_000_000),
+      makerTokenAmount: new BN(1_000_000),
+      totalAirdropAmountPerMilestone: new BN(14_000_000),
+      minBackers: new BN(1),
+      maxBackers: new BN(100),
+      softCap: new BN(0),
+      hardCap: new BN(5_000_000_000),
+      refundFees: REFUND_FEES,
+      withdrawalLockoutSeconds: WITHDRAWAL_LOCKOUT_SECONDS,
+      oversubscription: false,
+    };
+    let microTemplate: anchor.web3.PublicKey;
+
+    const findLaunchTemplatePDA = (templateId: BN) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from('launch_template'), templateId.toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )[0];
+
+    const createProposalWithTemplate = async (launchTemplate: anchor.web3.PublicKey) => {
+      const makerData = await program.account.makerAccount.fetch(makerAccount);
+      const testProposal = findProposalPDA(program.programId, maker.publicKey, makerData.proposalCount);
+      const testMint = anchor.web3.Keypair.generate();
+      const [testVault] = getTokenVaultAddress(vaultAuthority, testMint.publicKey, program.programId);
+
+      await program.methods
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, BACKING_DURATION, null)
+        .accountsPartial({
+          payer: authority.publicKey,
+          maker: maker.publicKey,
+          makerAccount,
+          vaultAuthority,
+          proposal: testProposal,
+          mintAccount: testMint.publicKey,
+          tokenVault: testVault,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          config: configStruct,
+          launchTemplate,
+        })
+        .signers([authority, testMint, maker])
+        .rpc()
+        .then(confirm);
+
+      return testProposal;
+    };
+
+    it('72. Fails to create a template whose supply cannot cover its allocations', async () => {
+      try {
+        await program.methods
+          .createLaunchTemplate(
+            'broken',
+            { ...MICRO_TERMS, totalMint: new BN(40_000_000) },
+            { equal: {} },
+            null
+          )
+          .accounts({
+            authority: configureAuthority.publicKey,
+          })
+          .signers([configureAuthority])
+          .rpc();
+
+        assert.fail('Should not allow a template with an over-allocated supply');
+      } catch (err) {
+        expect(err.message).to.include('InvalidConfig');
+      }
+    });
+
+    it('73. Configurator creates a "micro" template', async () => {
+      const configData = await program.account.configs.fetch(configStruct);
+      microTemplate = findLaunchTemplatePDA(configData.launchTemplateCount);
+      const eventPromise = waitForEvent(program, 'launchTemplateCreated');
+
+      await program.methods
+        .createLaunchTemplate('micro', MICRO_TERMS, { equal: {} }, null)
+        .accountsPartial({
+          authority: configureAuthority.publicKey,
+          launchTemplate: microTemplate,
+        })
+        .signers([configureAuthority])
+        .rpc()
+        .then(confirm);
+
+      const event = await eventPromise;
+      expect(event.name).to.equal('micro');
+      expect(event.templateId.toString()).to.equal(configData.launchTemplateCount.toString());
+
+      const template = await program.account.launchTemplate.fetch(microTemplate);
+      expect(template.retired).to.equal(false);
+      expect(template.terms.totalMint.toString()).to.equal(MICRO_TERMS.totalMint.toString());
+    });
+
+    it('74. A proposal created from a template takes its terms', async () => {
+      const template = await program.account.launchTemplate.fetch(microTemplate);
+      const eventPromise = waitForEvent(program, 'proposalCreated');
+
+      const testProposal = await createProposalWithTemplate(microTemplate);
+
+      const event = await eventPromise;
+      expect(event.launchTemplate.toString()).to.equal(template.templateId.toString());
+
+      const proposalData = await program.account.proposal.fetch(testProposal);
+      expect(proposalData.launchTemplate.toString()).to.equal(template.templateId.toString());
+      expect(proposalData.allocationMode).to.deep.equal({ equal: {} });
+      expect(proposalData.terms.totalMint.toString()).to.equal(MICRO_TERMS.totalMint.toString());
+      expect(proposalData.terms.maxAmountPerUser.toString()).to.equal(
+        MICRO_TERMS.maxAmountPerUser.toString()
+      );
+    });
+
+    it('74a. Fails when an override takes from the template\'s treasury share', async () => {
+      const configData = await program.account.configs.fetch(configStruct);
+      const splitTemplate = findLaunchTemplatePDA(configData.launchTemplateCount);
+      const templateSplit = { makerBps: 2_000, treasuryBps: 6_000, backerBps: 2_000 };
+
+      await program.methods
+        .createLaunchTemplate('treasury-heavy', MICRO_TERMS, { equal: {} }, templateSplit)
+        .accountsPartial({
+          authority: configureAuthority.publicKey,
+          launchTemplate: splitTemplate,
+        })
+        .signers([configureAuthority])
+        .rpc()
+        .then(confirm);
+
+      const makerData = await program.account.makerAccount.fetch(makerAccount);
+      const testProposal = findProposalPDA(program.programId, maker.publicKey, makerData.proposalCount);
+      const testMint = anchor.web3.Keypair.generate();
+      const [testVault] = getTokenVaultAddress(vaultAuthority, testMint.publicKey, program.programId);
+
+      // Valid against the global split, but below the template's treasury share
+      expect(configData.poolFeeSplit.treasuryBps).to.equal(4_000);
+      try {
+        await program.methods
+          .createProposal(
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+            BACKING_DURATION,
+            { makerBps: 4_000, treasuryBps: 4_000, backerBps: 2_000 }
+          )
+          .accountsPartial({
+            payer: authority.publicKey,
+            maker: maker.publicKey,
+            makerAccount,
+            vaultAuthority,
+            proposal: testProposal,
+            mintAccount: testMint.publicKey,
+            tokenVault: testVault,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            config: configStruct,
+            launchTemplate: splitTemplate,
+          })
+          .signers([authority, testMint, maker])
+          .rpc();
+
+        assert.fail('Should not allow an override that lowers the template\'s treasury share');
+      } catch (err) {
+        expect(err.message).to.include('InvalidFeeSplit');
+      }
+    });
+
+    it('75. Retired templates cannot be used for new proposals', async () => {
+      await program.methods
+        .retireLaunchTemplate()
+        .accountsPartial({
+          authority: configureAuthority.publicKey,
+          launchTemplate: microTemplate,
+        })
+        .signers([configureAuthority])
+        .rpc()
+        .then(confirm);
+
+      try {
+        await createProposalWithTemplate(microTemplate);
+        assert.fail('Should not allow a proposal from a retired template');
+      } catch (err) {
+        expect(err.message).to.include('LaunchTemplateRetired');
+      }
+    });
+  });
+
+  describe('Raise Cap Tests', () => {
+    const SOFT_CAP = new BN(1_200_000_000); // 1.2 SOL
+    const HARD_CAP = new BN(1_500_000_000); // 1.5 SOL
+    let capProposal: anchor.web3.PublicKey;
+    let capBacker: anchor.web3.Keypair;
+    let capBackerAta: anchor.web3.PublicKey;
+
+    const depositToCapProposal = (amount: BN) =>
+      program.methods
+        .depositSol(amount)
+        .accountsPartial({
+          backer: capBacker.publicKey,
+          mint: mintAccount,
+          userTokenAccount: capBackerAta,
+          proposal: capProposal,
+          backerAccount: findBackerAccountPDA(program.programId, capProposal, capBacker.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+          config: configStruct,
+        })
+        .signers([capBacker])
+        .rpc();
+
+    before(async () => {
+      await executeConfigUpdate(await queueConfigUpdate({ hardCap: [HARD_CAP] }));
+      await executeConfigUpdate(await queueConfigUpdate({ softCap: [SOFT_CAP] }));
+
+      const makerData = await program.account.makerAccount.fetch(makerAccount);
+      capProposal = findProposalPDA(program.programId, maker.publicKey, makerData.proposalCount);
+      const capMint = anchor.web3.Keypair.generate();
+      const [capVault] = getTokenVaultAddress(vaultAuthority, capMint.publicKey, program.programId);
+      capBacker = anchor.web3.Keypair.generate();
+      capBackerAta = findUserAta(capBacker.publicKey, mintAccount);
+
+      await provider.sendAndConfirm(new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: provider.wallet.publicKey,
+          toPubkey: capBacker.publicKey,
+          lamports: 3e9, // 3 SOL to cover deposits + transaction fees
+        })
+      )).then(confirm);
+
+      await program.methods
+        .mintSoulboundToUser()
+        .accounts({
+          authority: authority.publicKey,
+          user: capBacker.publicKey,
+          mint: mintAccount,
+          freezeAuthority,
+          mintAuthority,
+          userTokenAccount: capBackerAta,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      await program.methods
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, BACKING_DURATION, null)
+        .accountsPartial({
+          payer: authority.publicKey,
+          maker: maker.publicKey,
+          makerAccount,
+          vaultAuthority,
+          proposal: capProposal,
+          mintAccount: capMint.publicKey,
+          tokenVault: capVault,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          config: configStruct
+        })
+        .signers([authority, capMint, maker])
+        .rpc()
+        .then(confirm);
+    });
+
+    after(async () => {
+      await executeConfigUpdate(await queueConfigUpdate({ softCap: [new BN(0)] }));
+      await executeConfigUpdate(await queueConfigUpdate({ hardCap: [new BN(0)] }));
+    });
+
+    it('76. New proposals snapshot the backer limit and the caps', async () => {
+      const proposalData = await program.account.proposal.fetch(capProposal);
+      expect(proposalData.terms.maxBackers.toNumber()).to.equal(1000);
+      expect(proposalData.terms.softCap.toString()).to.equal(SOFT_CAP.toString());
+      expect(proposalData.terms.hardCap.toString()).to.equal(HARD_CAP.toString());
+    });
+
+    it('77. Fails when a deposit would exceed the hard cap', async () => {
+      try {
+        await depositToCapProposal(new BN(2_000_000_000));
+        assert.fail('Should not accept a deposit above the hard cap');
+      } catch (err) {
+        expect(err.message).to.include('HardCapExceeded');
+      }
+    });
+
+    it('78. Accepts a deposit that fits under the hard cap', async () => {
+      await depositToCapProposal(BACKING_AMOUNT).then(confirm);
+
+      const proposalData = await program.account.proposal.fetch(capProposal);
+      expect(proposalData.totalBacking.toString()).to.equal(BACKING_AMOUNT.toString());
+    });
+
+    it('78a. migrate_backer rejects a backer already in the current layout', async () => {
+      try {
+        await program.methods
+          .migrateBacker()
+          .accountsPartial({
+            payer: capBacker.publicKey,
+            backer: capBacker.publicKey,
+            proposal: capProposal,
+            backerAccount: findBackerAccountPDA(program.programId, capProposal, capBacker.publicKey),
+          })
+          .signers([capBacker])
+          .rpc();
+
+        assert.fail('Should not migrate a backer twice');
+      } catch (err) {
+        expect(err.message).to.include('BackerAlreadyMigrated');
+      }
+    });
+
+    it('79. Fails to queue a soft cap above the hard cap', async () => {
+      try {
+        await queueConfigUpdate({ softCap: [HARD_CAP.addn(1)] });
+        assert.fail('Should not allow a soft cap above the hard cap');
+      } catch (err) {
+        expect(err.message).to.include('InvalidConfig');
+      }
+    });
+
+    it('80. Fails to queue a backer limit below min_backers', async () => {
+      try {
+        await queueConfigUpdate({ maxBackers: [new BN(0)] });
+        assert.fail('Should not allow max_backers below min_backers');
+      } catch (err) {
+        expect(err.message).to.include('InvalidConfig');
+      }
+    });
+  });
+
+  describe('Oversubscription Tests', () => {
+    const HARD_CAP = new BN(1_000_000_000); // 1 SOL target
+    let overProposal: anchor.web3.PublicKey;
+    let overBacker: anchor.web3.Keypair;
+    let overBackerAta: anchor.web3.PublicKey;
+
+    before(async () => {
+      await executeConfigUpdate(await queueConfigUpdate({ hardCap: [HARD_CAP] }));
+      await executeConfigUpdate(await queueConfigUpdate({ oversubscription: [true] }));
+
+      const makerData = await program.account.makerAccount.fetch(makerAccount);
+      overProposal = findProposalPDA(program.programId, maker.publicKey, makerData.proposalCount);
+      const overMint = anchor.web3.Keypair.generate();
+      const [overVault] = getTokenVaultAddress(vaultAuthority, overMint.publicKey, program.programId);
+      overBacker = anchor.web3.Keypair.generate();
+      overBackerAta = findUserAta(overBacker.publicKey, mintAccount);
+
+      await provider.sendAndConfirm(new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: provider.wallet.publicKey,
+          toPubkey: overBacker.publicKey,
+          lamports: 3e9, // 3 SOL to cover the oversubscribed deposit + transaction fees
+        })
+      )).then(confirm);
+
+      await program.methods
+        .mintSoulboundToUser()
+        .accounts({
+          authority: authority.publicKey,
+          user: overBacker.publicKey,
+          mint: mintAccount,
+          freezeAuthority,
+          mintAuthority,
+          userTokenAccount: overBackerAta,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      await program.methods
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, LAUNCH_BACKING_DURATION, null)
+        .accountsPartial({
+          payer: authority.publicKey,
+          maker: maker.publicKey,
+          makerAccount,
+          vaultAuthority,
+          proposal: overProposal,
+          mintAccount: overMint.publicKey,
+          tokenVault: overVault,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          config: configStruct
+        })
+        .signers([authority, overMint, maker])
+        .rpc()
+        .then(confirm);
+    });
+
+    after(async () => {
+      await executeConfigUpdate(await queueConfigUpdate({ oversubscription: [false] }));
+      await executeConfigUpdate(await queueConfigUpdate({ hardCap: [new BN(0)] }));
+    });
+
+    it('81. Fails to clear the hard cap while oversubscription is on', async () => {
+      try {
+        await queueConfigUpdate({ hardCap: [new BN(0)] });
+        assert.fail('Should not allow oversubscription without a hard cap');
+      } catch (err) {
+        expect(err.message).to.include('InvalidConfig');
+      }
+    });
+
+    it('82. Accepts a deposit above the hard cap on an oversubscribed proposal', async () => {
+      const deposit = new BN(1_500_000_000);
+      await program.methods
+        .depositSol(deposit)
+        .accountsPartial({
+          backer: overBacker.publicKey,
+          mint: mintAccount,
+          userTokenAccount: overBackerAta,
+          proposal: overProposal,
+          backerAccount: findBackerAccountPDA(program.programId, overProposal, overBacker.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+          config: configStruct,
+        })
+        .signers([overBacker])
+        .rpc()
+        .then(confirm);
+
+      const proposalData = await program.account.proposal.fetch(overProposal);
+      expect(proposalData.terms.oversubscription).to.equal(true);
+      expect(proposalData.totalBacking.toString()).to.equal(deposit.toString());
+    });
+
+    it('83. Fails to claim excess before backing ends', async () => {
+      try {
+        await program.methods
+          .claimExcess()
+          .accountsPartial({
+            backer: overBacker.publicKey,
+            proposal: overProposal,
+            backerAccount: findBackerAccountPDA(program.programId, overProposal, overBacker.publicKey),
+            config: configStruct,
+          })
+          .signers([overBacker])
+          .rpc();
+
+        assert.fail('Should not allow claiming excess while backing is open');
+      } catch (err) {
+        expect(err.message).to.include('BackingNotEnded');
+      }
+    });
+
+    it('83a. Pays back the deposit above the hard cap once the proposal is finalized', async () => {
+      await finalizeAfterBacking(overProposal);
+
+      const overBackerAccount = findBackerAccountPDA(program.programId, overProposal, overBacker.publicKey);
+      const overEscrow = findProposalEscrowPDA(program.programId, overProposal);
+      const proposalBefore = await program.account.proposal.fetch(overProposal);
+      expect(proposalBefore.status).to.deep.equal({ succeeded: {} });
+
+      // A single backer of 1.5 SOL against a 1 SOL cap gets the whole 0.5 SOL excess
+      const expectedExcess = proposalBefore.totalBacking.sub(HARD_CAP);
+      const escrowBefore = await provider.connection.getBalance(overEscrow);
+      const backerBefore = await provider.connection.getBalance(overBacker.publicKey);
+      const eventPromise = waitForEvent(program, 'excessClaimed');
+
+      await program.methods
+        .claimExcess()
+        .accountsPartial({
+          backer: overBacker.publicKey,
+          proposal: overProposal,
+          backerAccount: overBackerAccount,
+          config: configStruct,
+        })
+        .signers([overBacker])
+        .rpc()
+        .then(confirm);
+
+      const event = await eventPromise;
+      expect(event.amount.toString()).to.equal(expectedExcess.toString());
+
+      const escrowAfter = await provider.connection.getBalance(overEscrow);
+      const backerAfter = await provider.connection.getBalance(overBacker.publicKey);
+      expect(escrowBefore - escrowAfter).to.equal(expectedExcess.toNumber());
+      expect(backerAfter - backerBefore).to.be.closeTo(expectedExcess.toNumber(), 10_000); // Allow for the tx fee
+
+      const backerData = await program.account.backers.fetch(overBackerAccount);
+      expect(backerData.excessRefunded.toString()).to.equal(expectedExcess.toString());
+      const proposalAfter = await program.account.proposal.fetch(overProposal);
+      expect(proposalAfter.excessRefunded.toString()).to.equal(expectedExcess.toString());
+
+      // The excess is paid out once
+      try {
+        await program.methods
+          .claimExcess()
+          .accountsPartial({
+            backer: overBacker.publicKey,
+            proposal: overProposal,
+            backerAccount: overBackerAccount,
+            config: configStruct,
+          })
+          .signers([overBacker])
+          .rpc();
+
+        assert.fail('Should not allow claiming the excess twice');
+      } catch (err) {
+        expect(err.message).to.include('NoExcessToClaim');
+      }
+    });
+
+    it('83b. Refunds only the capped part of the deposit after the excess was claimed', async () => {
+      await program.methods
+        .rejectProposal()
+        .accountsPartial({
+          authority: authority.publicKey,
+          proposal: overProposal,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      const overBackerAccount = findBackerAccountPDA(program.programId, overProposal, overBacker.publicKey);
+      const overEscrow = findProposalEscrowPDA(program.programId, overProposal);
+      const backerData = await program.account.backers.fetch(overBackerAccount);
+      const refundable = backerData.depositAmount.sub(backerData.excessRefunded);
+      expect(refundable.toString()).to.equal(HARD_CAP.toString());
+
+      const escrowBefore = await provider.connection.getBalance(overEscrow);
+      const eventPromise = waitForEvent(program, 'backerRefunded');
+
+      await program.methods
+        .refund()
+        .accountsPartial({
+          backer: overBacker.publicKey,
+          proposal: overProposal,
+          weweTreasury,
+          backerAccount: overBackerAccount,
+          backerProposalCount: findBackerProposalCountPDA(program.programId, overBacker.publicKey),
+          systemProgram: anchor.web3.SystemProgram.programId,
+          config: configStruct,
+        })
+        .rpc()
+        .then(confirm);
+
+      const event = await eventPromise;
+      expect(event.cause).to.deep.equal({ platformRejected: {} });
+      expect(event.refundAmount.add(event.weweFee).toString()).to.equal(refundable.toString());
+
+      // The escrow pays out the rest of the deposit and nothing of the excess again
+      const escrowAfter = await provider.connection.getBalance(overEscrow);
+      expect(escrowBefore - escrowAfter).to.equal(refundable.toNumber());
+
+      const proposalAfter = await program.account.proposal.fetch(overProposal);
+      expect(proposalAfter.totalBacking.toNumber()).to.equal(0);
+      expect(proposalAfter.excessRefunded.toNumber()).to.equal(0);
+      expect(await provider.connection.getAccountInfo(overBackerAccount)).to.be.null;
+    });
+  });
+
+  describe('Deposit-Weighted Allocation Tests', () => {
+    // Deposits of 1 and 2 SOL split every milestone pool one third to two thirds,
+    // which leaves rounding dust behind
+    const DEPOSITS = [new BN(1_000_000_000), new BN(2_000_000_000)];
+    const UNIT = new BN(10).pow(new BN(9));
+    let weightedProposal: anchor.web3.PublicKey;
+    let weightedMint: anchor.web3.Keypair;
+    let weightedVault: anchor.web3.PublicKey;
+    let weightedBackers: anchor.web3.Keypair[];
+
+    const weightedBackerAccount = (b: anchor.web3.Keypair) =>
+      findBackerAccountPDA(program.programId, weightedProposal, b.publicKey);
+
+    before(async () => {
+      const makerData = await program.account.makerAccount.fetch(makerAccount);
+      weightedProposal = findProposalPDA(program.programId, maker.publicKey, makerData.proposalCount);
+      weightedMint = anchor.web3.Keypair.generate();
+      [weightedVault] = getTokenVaultAddress(vaultAuthority, weightedMint.publicKey, program.programId);
+      weightedBackers = DEPOSITS.map(() => anchor.web3.Keypair.generate());
+
+      for (const b of weightedBackers) {
+        await provider.sendAndConfirm(new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: provider.wallet.publicKey,
+            toPubkey: b.publicKey,
+            lamports: 3e9, // Covers the largest deposit + transaction fees
+          })
+        )).then(confirm);
+
+        await program.methods
+          .mintSoulboundToUser()
+          .accounts({
+            authority: authority.publicKey,
+            user: b.publicKey,
+            mint: mintAccount,
+            freezeAuthority,
+            mintAuthority,
+            userTokenAccount: findUserAta(b.publicKey, mintAccount),
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc()
+          .then(confirm);
+      }
+
+      await program.methods
+        .createProposal(metadata.name, metadata.symbol, metadata.uri, LAUNCH_BACKING_DURATION, null)
+        .accountsPartial({
+          payer: authority.publicKey,
+          maker: maker.publicKey,
+          makerAccount,
+          vaultAuthority,
+          proposal: weightedProposal,
+          mintAccount: weightedMint.publicKey,
+          tokenVault: weightedVault,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          config: configStruct
+        })
+        .signers([authority, weightedMint, maker])
+        .rpc()
+        .then(confirm);
+
+      for (const [i, b] of weightedBackers.entries()) {
+        await program.methods
+          .depositSol(DEPOSITS[i])
+          .accountsPartial({
+            backer: b.publicKey,
+            weweVault: weweTreasury,
+            mint: mintAccount,
+            userTokenAccount: findUserAta(b.publicKey, mintAccount),
+            proposal: weightedProposal,
+            backerAccount: weightedBackerAccount(b),
+            vaultAuthority,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            config: configStruct,
+          })
+          .signers([b])
+          .rpc()
+          .then(confirm);
+      }
+
+      await finalizeAfterBacking(weightedProposal);
+
+      const weightedPdas = derivePoolPDAs(program.programId, cpAmm.programId, weightedMint.publicKey, WSOL_MINT, maker.publicKey, config);
+      const [wsolVault] = getTokenVaultAddress(vaultAuthority, WSOL_MINT, program.programId);
+      const tx = await program.methods
+        .createPool(await getLaunchSqrtPrice(weightedProposal), null)
+        .accountsPartial({
+          proposal: weightedProposal,
+          vaultAuthority,
+          maker: maker.publicKey,
+          tokenVault: weightedVault,
+          wsolVault,
+          poolAuthority: weightedPdas.poolAuthority,
+          dammPoolAuthority: weightedPdas.poolAuthority,
+          poolConfig: config,
+          pool: weightedPdas.pool,
+          positionNftMint: weightedPdas.positionNftMint.publicKey,
+          positionNftAccount: weightedPdas.positionNftAccount,
+          position: weightedPdas.position,
+          ammProgram: cpAmm.programId,
+          baseMint: weightedMint.publicKey,
+          mintAccount: weightedMint.publicKey,
+          makerTokenAccount: weightedPdas.makerTokenAccount,
+          quoteMint: WSOL_MINT,
+          tokenAVault: weightedPdas.tokenAVault,
+          tokenBVault: weightedPdas.tokenBVault,
+          payer: authority.publicKey,
+          tokenBaseProgram: TOKEN_PROGRAM_ID,
+          tokenQuoteProgram: TOKEN_PROGRAM_ID,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          dammEventAuthority: weightedPdas.dammEventAuthority,
+          weweTreasury,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          config: configStruct,
+        })
+        .signers([authority, weightedPdas.positionNftMint])
+        .transaction();
+      tx.instructions.unshift(ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }));
+      await provider.sendAndConfirm(tx, [authority, weightedPdas.positionNftMint]);
+    });
+
+    it('84. Airdrops each backer a share weighted by deposit and records the dust', async () => {
+      const proposalData = await program.account.proposal.fetch(weightedProposal);
+      expect(proposalData.allocationMode).to.deep.equal({ depositWeighted: {} });
+      const pool = proposalData.terms.totalAirdropAmountPerMilestone;
+      const totalBacking = proposalData.totalBacking;
+
+      let remainderSum = new BN(0);
+      for (const [i, b] of weightedBackers.entries()) {
+        const backerTokenAccount = findUserAta(b.publicKey, weightedMint.publicKey);
+        await program.methods
+          .airdrop()
+          .accounts({
+            payer: authority.publicKey,
+            backer: b.publicKey,
+            proposal: weightedProposal,
+            vaultAuthority,
+            mintAccount: weightedMint.publicKey,
+            tokenVault: weightedVault,
+            backerAccount: weightedBackerAccount(b),
+            backerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            config: configStruct,
+          })
+          .signers([authority])
+          .rpc()
+          .then(confirm);
+
+        // share = pool * deposit / total_backing, rounded down to whole tokens
+        const expectedShare = pool.mul(DEPOSITS[i]).div(totalBacking);
+        remainderSum = remainderSum.add(pool.mul(DEPOSITS[i]).mod(totalBacking));
+        const balance = await provider.connection.getTokenAccountBalance(backerTokenAccount);
+        expect(balance.value.amount).to.equal(expectedShare.mul(UNIT).toString());
+      }
+
+      // The rounded-away parts add up to whole units of airdrop dust
+      const airdropped = await program.account.proposal.fetch(weightedProposal);
+      expect(airdropped.roundingDust.toString()).to.equal(remainderSum.div(totalBacking).toString());
+      expect(airdropped.airdropDustRemainder.toString()).to.equal(remainderSum.mod(totalBacking).toString());
+      expect(airdropped.roundingDust.gtn(0)).to.equal(true);
+    });
+
+    it('85. Ending a milestone burns the milestone and airdrop dust', async () => {
+      const proposalData = await program.account.proposal.fetch(weightedProposal);
+      const pool = proposalData.terms.totalAirdropAmountPerMilestone;
+      const totalBacking = proposalData.totalBacking;
+
+      await program.methods
+        .initialiseMilestone()
+        .accounts({
+          authority: authority.publicKey,
+          proposal: weightedProposal,
+          mintAccount: weightedMint.publicKey,
+          metadataAccount: findMetadataPDA(weightedMint.publicKey),
+          payer: authority.publicKey,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      let shareSum = new BN(0);
+      for (const [i, b] of weightedBackers.entries()) {
+        await program.methods
+          .snapshotBackerAmount()
+          .accounts({
+            authority: authority.publicKey,
+            proposal: weightedProposal,
+            backer: b.publicKey,
+            backerAccount: weightedBackerAccount(b),
+            backerTokenAccount: findUserAta(b.publicKey, weightedMint.publicKey),
+            mintAccount: weightedMint.publicKey,
+            config: configStruct,
+          })
+          .signers([authority])
+          .rpc()
+          .then(confirm);
+
+        const backerData = await program.account.backers.fetch(weightedBackerAccount(b));
+        const expectedShare = pool.mul(DEPOSITS[i]).div(totalBacking);
+        // Both backers still hold their whole airdrop, so they get their full share
+        expect(backerData.claimAmount.toString()).to.equal(expectedShare.toString());
+        shareSum = shareSum.add(expectedShare);
+      }
+
+      const snapshotted = await program.account.proposal.fetch(weightedProposal);
+      expect(snapshotted.milestoneShareSum.toString()).to.equal(shareSum.toString());
+      const expectedDust = pool.sub(shareSum).add(snapshotted.roundingDust);
+
+      const supplyBefore = (await getMint(provider.connection, weightedMint.publicKey)).supply;
+      const vaultBefore = await provider.connection.getTokenAccountBalance(weightedVault);
+      const eventPromise = waitForEvent(program, 'milestoneEnded');
+
+      await program.methods
+        .endMilestone()
+        .accounts({
+          authority: authority.publicKey,
+          proposal: weightedProposal,
+          mint: weightedMint.publicKey,
+          vaultAuthority,
+          tokenVault: weightedVault,
+          config: configStruct,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([authority])
+        .rpc()
+        .then(confirm);
+
+      // Full reputation means nothing but the dust is burned
+      const event = await eventPromise;
+      expect(event.dustUnits.toString()).to.equal(expectedDust.toString());
+      expect(event.burnedUnits.toString()).to.equal(expectedDust.mul(UNIT).toString());
+
+      const supplyAfter = (await getMint(provider.connection, weightedMint.publicKey)).supply;
+      const vaultAfter = await provider.connection.getTokenAccountBalance(weightedVault);
+      expect((supplyBefore - supplyAfter).toString()).to.equal(expectedDust.mul(UNIT).toString());
+      expect(
+        new BN(vaultBefore.value.amount).sub(new BN(vaultAfter.value.amount)).toString()
+      ).to.equal(expectedDust.mul(UNIT).toString());
+
+      const ended = await program.account.proposal.fetch(weightedProposal);
+      expect(ended.roundingDust.toString()).to.equal('0');
+      expect(ended.status).to.deep.equal({ launched: {} });
+    });
+  });
+
+  describe('Proposal Migration Tests', () => {
+    // Loaded from genesis/legacy_proposal.json: a Backing proposal in the layout
+    // before ProposalStatus, with 2 backers and 2 SOL still in the shared vault
+    const legacyProposal = new anchor.web3.PublicKey('B3Sg9Gj3PrcPyFu5EkyPiETiUs5yKjLw4FHiwjNTFNp');
+    const LEGACY_BACKING = new BN(2_000_000_000);
+    const legacyEscrow = findProposalEscrowPDA(program.programId, legacyProposal);
+
+    const migrateLegacyProposal = (poolAddress: anchor.web3.PublicKey | null, poolPosition: anchor.web3.PublicKey | null) =>
+      program.methods
+        .migrateProposal(poolAddress, poolPosition)
+        .accountsPartial({
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          proposal: legacyProposal,
+          proposalEscrow: legacyEscrow,
+          vaultAuthority,
+          config: configStruct,
+        })
+        .signers([authority])
+        .rpc();
+
+    before(async () => {
+      // The legacy backing sat in the vault authority, so fund it as deposit_sol used to
+      await provider.sendAndConfirm(new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: provider.wallet.publicKey,
+          toPubkey: vaultAuthority,
+          lamports: LEGACY_BACKING.toNumber(),
+        })
+      )).then(confirm);
+    });
+
+    it('86. Fails to migrate a proposal that never launched with pool accounts', async () => {
+      try {
+        await migrateLegacyProposal(anchor.web3.Keypair.generate().publicKey, anchor.web3.Keypair.generate().publicKey);
+        assert.fail('Should not record a pool for a proposal that never launched');
+      } catch (err) {
+        expect(err.message).to.include('IncorrectAccount');
+      }
+    });
+
+    it('87. Migrates a legacy proposal and moves its backing into the proposal escrow', async () => {
+      const vaultBefore = await provider.connection.getBalance(vaultAuthority);
+      const escrowBefore = await provider.connection.getBalance(legacyEscrow);
+      expect(escrowBefore).to.equal(0);
+
+      await migrateLegacyProposal(null, null).then(confirm);
+
+      // The payer covers the escrow rent, the backing comes out of the shared vault
+      const escrowRent = await provider.connection.getMinimumBalanceForRentExemption(0);
+      const vaultAfter = await provider.connection.getBalance(vaultAuthority);
+      const escrowAfter = await provider.connection.getBalance(legacyEscrow);
+      expect(vaultBefore - vaultAfter).to.equal(LEGACY_BACKING.toNumber());
+      expect(escrowAfter).to.equal(escrowRent + LEGACY_BACKING.toNumber());
+
+      const proposalData = await program.account.proposal.fetch(legacyProposal);
+      expect(proposalData.status).to.deep.equal({ backing: {} });
+      expect(proposalData.rejectionCause).to.be.null;
+      expect(proposalData.totalBackers.toNumber()).to.equal(2);
+      expect(proposalData.totalBacking.toString()).to.equal(LEGACY_BACKING.toString());
+      expect(proposalData.allocationMode).to.deep.equal({ equal: {} });
+      expect(proposalData.backingEndsAt.toNumber()).to.equal(proposalData.timeStarted.toNumber() + 3 * 86_400);
+      expect(proposalData.rentPayer.toBase58()).to.equal(authority.publicKey.toBase58());
+      expect(proposalData.poolAddress).to.be.null;
+      expect(proposalData.poolPosition).to.be.null;
+      expect(proposalData.tokenName).to.equal('Legacy Token');
+      expect(proposalData.tokenSymbol).to.equal('LEGACY');
+
+      // Terms and launch settings come from the live config at migration time
+      const configData = await program.account.configs.fetch(configStruct);
+      expect(proposalData.terms.totalPoolTokens.toString()).to.equal(configData.totalPoolTokens.toString());
+      expect(proposalData.launchTerms.poolFeeSchedule.cliffFeeNumerator.toString())
+        .to.equal(configData.poolFeeSchedule.cliffFeeNumerator.toString());
+    });
+
+    it('88. Fails to migrate a proposal already in the current layout', async () => {
+      try {
+        await migrateLegacyProposal(null, null);
+        assert.fail('Should not migrate a proposal twice');
+      } catch (err) {
+        expect(err.message).to.include('ProposalAlreadyMigrated');
+      }
+    });
+  });
 });